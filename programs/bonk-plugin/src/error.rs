@@ -30,4 +30,28 @@ pub enum BonkPluginError {
     ActionTargetMismatch,
     #[msg("Maximum deposits length reached")]
     MaximumDepositsReached,
+    #[msg("Invalid Realm for Registrar")]
+    InvalidRealmForRegistrar,
+    #[msg("Invalid MaxVoterWeightRecord Realm")]
+    InvalidMaxVoterWeightRecordRealm,
+    #[msg("Invalid MaxVoterWeightRecord Mint")]
+    InvalidMaxVoterWeightRecordMint,
+    #[msg("The vault does not match the Stake Pool")]
+    InvalidStakePoolVault,
+    #[msg("Input max voter weight is required")]
+    MissingInputMaxVoterWeight,
+    #[msg("The Stake Pool accounts count does not match the Registrar")]
+    StakePoolAccountsCountMismatch,
+    #[msg("The voter weight of the VoterWeightRecord has not expired yet")]
+    VoterWeightRecordNotExpired,
+    #[msg("Closing VoterWeightRecord is not allowed by the Registrar")]
+    VoterWeightRecordCloseNotAllowed,
+    #[msg("Vote weight arithmetic overflow")]
+    VoteWeightOverflow,
+    #[msg("Resizing max stake pools below the number of configured stake pools is not allowed")]
+    InvalidResizeMaxStakePools,
+    #[msg("Registrar has already been migrated")]
+    RegistrarAlreadyMigrated,
+    #[msg("Stake Pool max lockup duration must be captured using ConfigureStakePool to apply lockup time decay")]
+    StakePoolMaxLockupDurationNotSet,
 }
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::state::VoterWeightAction;
+
+/// Weight contributed by a single StakeDepositReceipt
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq)]
+pub struct StakeDepositWeight {
+    /// The StakeDepositReceipt used to resolve the weight
+    pub stake_deposit_receipt: Pubkey,
+
+    /// The weight of the deposit after the Stake Pool weight and lockup decay were applied
+    pub weight: u64,
+}
+
+/// Emitted when VoterWeightRecord is updated using stake deposits
+#[event]
+pub struct VoterWeightRecordUpdated {
+    /// The VoterWeightRecord which was updated
+    pub voter_weight_record: Pubkey,
+
+    /// The voter (governing_token_owner) whose weight was updated
+    pub governing_token_owner: Pubkey,
+
+    /// The action the weight was resolved for
+    pub action: VoterWeightAction,
+
+    /// The target of the action (ex. Proposal)
+    pub action_target: Pubkey,
+
+    /// The StakeDepositReceipts used in the update and their individual weights
+    pub stake_deposit_weights: Vec<StakeDepositWeight>,
+
+    /// Voter weight resolved from the predecessor plugin or TokenOwnerRecord
+    pub predecessor_voter_weight: u64,
+
+    /// The total voter weight stored in VoterWeightRecord after the update
+    pub voter_weight: u64,
+}
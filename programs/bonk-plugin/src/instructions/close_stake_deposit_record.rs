@@ -0,0 +1,75 @@
+use crate::error::BonkPluginError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Closes StakeDepositRecord of the voter and returns its lamports to the beneficiary
+/// When close_voter_weight_record is set and the Registrar allows it the VoterWeightRecord is closed as well
+///
+/// The accounts can only be closed once the voter weight of the VoterWeightRecord has expired
+/// Note: The StakeDepositRecord can be recreated with CreateStakeDepositRecord when the VoterWeightRecord is kept
+#[derive(Accounts)]
+pub struct CloseStakeDepositRecord<'info> {
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        constraint = voter_weight_record.realm == registrar.realm
+        @ BonkPluginError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ BonkPluginError::InvalidVoterWeightRecordMint,
+
+        constraint = voter_weight_record.governing_token_owner == voter_authority.key()
+        @ BonkPluginError::InvalidTokenOwnerForVoterWeightRecord,
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+
+    #[account(
+        mut,
+        seeds = [
+          b"stake-deposit-record".as_ref(),
+          voter_weight_record.key().as_ref(),
+        ],
+        bump = stake_deposit_record.bump,
+        close = beneficiary
+    )]
+    pub stake_deposit_record: Account<'info, StakeDepositRecord>,
+
+    /// The governing_token_owner of the VoterWeightRecord
+    pub voter_authority: Signer<'info>,
+
+    /// CHECK: Beneficiary may be any address
+    #[account(mut)]
+    pub beneficiary: UncheckedAccount<'info>,
+}
+
+pub fn close_stake_deposit_record_handler(
+    ctx: Context<CloseStakeDepositRecord>,
+    close_voter_weight_record: bool,
+) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let voter_weight_record = &ctx.accounts.voter_weight_record;
+
+    // The weight can't be withdrawn from an ongoing spl-gov instruction and hence it must be expired
+    let current_slot = Clock::get()?.slot;
+
+    require!(
+        voter_weight_record
+            .voter_weight_expiry
+            .is_some_and(|voter_weight_expiry| voter_weight_expiry < current_slot),
+        BonkPluginError::VoterWeightRecordNotExpired
+    );
+
+    if close_voter_weight_record {
+        require!(
+            registrar.allow_voter_weight_record_close,
+            BonkPluginError::VoterWeightRecordCloseNotAllowed
+        );
+
+        ctx.accounts
+            .voter_weight_record
+            .close(ctx.accounts.beneficiary.to_account_info())?;
+    }
+
+    Ok(())
+}
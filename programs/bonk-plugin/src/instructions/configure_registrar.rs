@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use spl_governance::state::realm;
+
+use crate::{error::BonkPluginError, state::*};
+
+/// Configures the Bonk Registrar,
+/// allowing the stake weight mode, VoterWeightRecord closing, lockup time decay or previous plugin to be updated
+/// Stake Pools are configured using ConfigureStakePool
+/// The realm_authority stored in the Registrar is updated to the current Realm authority
+#[derive(Accounts)]
+#[instruction(stake_weight_mode: StakeWeightMode, allow_voter_weight_record_close: bool, lockup_time_decay: bool)]
+pub struct ConfigureRegistrar<'info> {
+    /// The Bonk Plugin Registrar to be updated
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    /// An spl-governance Realm
+    ///
+    /// Realm is validated in the instruction:
+    /// - Realm is owned by the governance_program_id
+    /// - realm_authority is realm.authority
+    ///
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    #[account(
+        address = registrar.realm @ BonkPluginError::InvalidRealmForRegistrar,
+        owner = registrar.governance_program_id
+    )]
+    pub realm: UncheckedAccount<'info>,
+
+    /// realm_authority must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// The previous voter weight plugin; the predecessor is removed when omitted
+    /// CHECK: The account data is not used
+    pub previous_voter_weight_plugin_program_id: Option<UncheckedAccount<'info>>,
+}
+
+pub fn configure_registrar_handler(
+    ctx: Context<ConfigureRegistrar>,
+    stake_weight_mode: StakeWeightMode,
+    allow_voter_weight_record_close: bool,
+    lockup_time_decay: bool,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    // Verify that realm_authority is the expected authority of the Realm
+    // and that the mint matches one of the realm mints too
+    let realm = realm::get_realm_data_for_governing_token_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.realm,
+        &registrar.governing_token_mint,
+    )?;
+
+    require!(
+        realm.authority == Some(ctx.accounts.realm_authority.key()),
+        BonkPluginError::InvalidRealmAuthority
+    );
+
+    registrar.realm_authority = ctx.accounts.realm_authority.key();
+    registrar.stake_weight_mode = stake_weight_mode;
+    registrar.allow_voter_weight_record_close = allow_voter_weight_record_close;
+    // Stake Pools migrated from the original Registrar layout must be configured before lockup time decay is enabled
+    if lockup_time_decay {
+        require!(
+            registrar
+                .stake_pool_configs
+                .iter()
+                .all(|stake_pool_config| stake_pool_config.max_lockup_duration > 0),
+            BonkPluginError::StakePoolMaxLockupDurationNotSet
+        );
+    }
+
+    registrar.lockup_time_decay = lockup_time_decay;
+    registrar.previous_voter_weight_plugin_program_id = ctx
+        .accounts
+        .previous_voter_weight_plugin_program_id
+        .as_ref()
+        .map(|previous_voter_weight_plugin_program_info| {
+            previous_voter_weight_plugin_program_info.key()
+        });
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use spl_governance::state::realm;
+
+use crate::{error::BonkPluginError, state::*, utils::stake_pool::StakePool};
+
+/// Configures Stake Pool which can be used for governance and its weight
+/// The Stake Pool is added to the Registrar or its weight updated when it's already configured
+/// Setting the weight to 0 removes the Stake Pool from the Registrar
+#[derive(Accounts)]
+#[instruction(weight: u64)]
+pub struct ConfigureStakePool<'info> {
+    /// Registrar for which we configure this Stake Pool
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    #[account(
+        address = registrar.realm @ BonkPluginError::InvalidRealmForRegistrar,
+        owner = registrar.governance_program_id
+    )]
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// The Stake Pool to configure
+    /// CHECK: Owned by spl-token-staking instance specified in registrar.staking_program_id
+    #[account(
+        owner = registrar.staking_program_id,
+    )]
+    pub stake_pool: AccountInfo<'info>,
+}
+
+pub fn configure_stake_pool_handler(ctx: Context<ConfigureStakePool>, weight: u64) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let realm = realm::get_realm_data_for_governing_token_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.realm,
+        &registrar.governing_token_mint,
+    )?;
+
+    require!(
+        realm.authority == Some(ctx.accounts.realm_authority.key()),
+        BonkPluginError::InvalidRealmAuthority
+    );
+
+    let stake_pool = StakePool::deserialize_checked(&ctx.accounts.stake_pool)?;
+
+    require!(
+        stake_pool.mint == registrar.governing_token_mint,
+        BonkPluginError::InvalidGoverningToken
+    );
+
+    let stake_pool_idx = registrar
+        .stake_pool_configs
+        .iter()
+        .position(|spc| spc.stake_pool == ctx.accounts.stake_pool.key());
+
+    match (stake_pool_idx, weight) {
+        (Some(stake_pool_idx), 0) => {
+            registrar.stake_pool_configs.remove(stake_pool_idx);
+        }
+        (Some(stake_pool_idx), weight) => {
+            let stake_pool_config = &mut registrar.stake_pool_configs[stake_pool_idx];
+            stake_pool_config.weight = weight;
+            stake_pool_config.max_lockup_duration = stake_pool.max_duration;
+        }
+        (None, 0) => return err!(BonkPluginError::InvalidStakePool),
+        (None, weight) => {
+            // Note: push() would throw an error on serialization if we exceed
+            // max_stake_pools the Registrar was created or resized with
+            registrar.stake_pool_configs.push(StakePoolConfig {
+                stake_pool: ctx.accounts.stake_pool.key(),
+                weight,
+                max_lockup_duration: stake_pool.max_duration,
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -2,14 +2,13 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 use spl_governance::state::realm;
 
-use crate::{
-    error::BonkPluginError, state::*, utils::stake_pool::StakePool, SPL_TOKEN_STAKING_PROGRAM_ID,
-};
+use crate::{error::BonkPluginError, state::*, utils::stake_pool::StakePool};
 
 /// Creates Registrar storing Stake Pool details for Bonk
 /// This instruction should only be executed once per realm/governing_token_mint to create the account
+/// The given stake_pool is registered with weight 1 and further Stake Pools can be added using ConfigureStakePool
 #[derive(Accounts)]
-#[instruction()]
+#[instruction(stake_weight_mode: StakeWeightMode, max_stake_pools: u8)]
 pub struct CreateRegistrar<'info> {
     #[account(
     init,
@@ -19,7 +18,7 @@ pub struct CreateRegistrar<'info> {
       governing_token_mint.key().as_ref()],
     bump,
     payer = payer,
-    space = 8 + Registrar::INIT_SPACE
+    space = Registrar::get_space(max_stake_pools)
   )]
     pub registrar: Account<'info, Registrar>,
 
@@ -34,9 +33,14 @@ pub struct CreateRegistrar<'info> {
     #[account(owner = governance_program_id.key())]
     pub realm: UncheckedAccount<'info>,
 
-    /// CHECK: Owned by SPL Staking Program
+    /// The program id of the spl-token-staking program the stake pools belong to
+    /// CHECK: Can be any instance of spl-token-staking and it's not known at the compilation time
+    #[account(executable)]
+    pub staking_program_id: UncheckedAccount<'info>,
+
+    /// CHECK: Owned by spl-token-staking instance specified in staking_program_id
     #[account(
-        owner = SPL_TOKEN_STAKING_PROGRAM_ID,
+        owner = staking_program_id.key(),
     )]
     pub stake_pool: AccountInfo<'info>,
 
@@ -47,13 +51,25 @@ pub struct CreateRegistrar<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn create_registrar_handler(ctx: Context<CreateRegistrar>) -> Result<()> {
+/// max_stake_pools is used to allocate account size for the maximum number of Stake Pools
+pub fn create_registrar_handler(
+    ctx: Context<CreateRegistrar>,
+    stake_weight_mode: StakeWeightMode,
+    _max_stake_pools: u8,
+) -> Result<()> {
     let registrar = &mut ctx.accounts.registrar;
     registrar.governance_program_id = ctx.accounts.governance_program_id.key();
     registrar.realm = ctx.accounts.realm.key();
     registrar.realm_authority = ctx.accounts.realm_authority.key();
     registrar.governing_token_mint = ctx.accounts.governing_token_mint.key();
     registrar.stake_pool = ctx.accounts.stake_pool.key();
+    registrar.staking_program_id = ctx.accounts.staking_program_id.key();
+    registrar.stake_pool_configs = vec![StakePoolConfig {
+        stake_pool: ctx.accounts.stake_pool.key(),
+        weight: 1,
+        max_lockup_duration: 0,
+    }];
+    registrar.stake_weight_mode = stake_weight_mode;
 
     if let Some(previous_voter_weight_plugin_program_info) =
         &ctx.accounts.previous_voter_weight_plugin_program_id
@@ -72,6 +88,8 @@ pub fn create_registrar_handler(ctx: Context<CreateRegistrar>) -> Result<()> {
 
     let stake_pool = StakePool::deserialize_checked(&ctx.accounts.stake_pool)?;
 
+    registrar.stake_pool_configs[0].max_lockup_duration = stake_pool.max_duration;
+
     require!(
         realm.authority.unwrap() == ctx.accounts.realm_authority.key(),
         BonkPluginError::InvalidRealmAuthority
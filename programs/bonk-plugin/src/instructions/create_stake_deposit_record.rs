@@ -0,0 +1,55 @@
+use crate::error::BonkPluginError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Creates StakeDepositRecord for an existing VoterWeightRecord
+/// StakeDepositRecord is created together with VoterWeightRecord and this instruction is only needed
+/// to recreate it after it was closed with CloseStakeDepositRecord while the VoterWeightRecord was kept
+///
+/// The voter weight of the VoterWeightRecord is reset because it can't be reconciled with the empty deposits
+#[derive(Accounts)]
+pub struct CreateStakeDepositRecord<'info> {
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        constraint = voter_weight_record.realm == registrar.realm
+        @ BonkPluginError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ BonkPluginError::InvalidVoterWeightRecordMint,
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+
+    #[account(
+    init,
+    seeds = [
+      b"stake-deposit-record".as_ref(),
+      voter_weight_record.key().as_ref(),
+    ],
+    bump,
+    payer = payer,
+    space = StakeDepositRecord::INIT_SPACE
+  )]
+    pub stake_deposit_record: Account<'info, StakeDepositRecord>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_stake_deposit_record_handler(ctx: Context<CreateStakeDepositRecord>) -> Result<()> {
+    let stake_deposit_record = &mut ctx.accounts.stake_deposit_record;
+
+    stake_deposit_record.init(ctx.bumps.stake_deposit_record);
+
+    // The weight accumulated for the previous action and target was tracked by the closed StakeDepositRecord
+    // and must not be added on top of when the same action and target is updated again
+    let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+    voter_weight_record.voter_weight = 0;
+    voter_weight_record.weight_action = None;
+    voter_weight_record.weight_action_target = None;
+
+    Ok(())
+}
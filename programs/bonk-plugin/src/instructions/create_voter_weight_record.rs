@@ -31,7 +31,7 @@ pub struct CreateVoterWeightRecord<'info> {
     ],
     bump,
     payer = payer,
-    space = StakeDepositRecord::FIXED_LEN + 8 * 32
+    space = StakeDepositRecord::INIT_SPACE
   )]
     pub stake_deposit_record: Account<'info, StakeDepositRecord>,
 
@@ -57,10 +57,7 @@ pub fn create_voter_weight_record_handler(
     // Set expiry to expired
     voter_weight_record.voter_weight_expiry = Some(0);
 
-    stake_deposit_record.weight_action_target = None;
-    stake_deposit_record.weight_action = None;
-    stake_deposit_record.deposits_len = 8;
-    stake_deposit_record.bump = ctx.bumps.stake_deposit_record;
-    stake_deposit_record.previous_voter_weight = 0;
+    stake_deposit_record.init(ctx.bumps.stake_deposit_record);
+
     Ok(())
 }
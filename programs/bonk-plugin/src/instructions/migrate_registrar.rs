@@ -0,0 +1,66 @@
+use anchor_lang::{prelude::*, system_program, Discriminator};
+
+use crate::{error::BonkPluginError, state::*};
+
+/// Migrates Registrar with the original account layout which only stored a single Stake Pool
+/// The legacy Stake Pool is configured with weight 1 for the canonical spl-token-staking program
+/// The instruction is permissionless because the migrated Registrar grants the same voter weights
+#[derive(Accounts)]
+pub struct MigrateRegistrar<'info> {
+    /// The Registrar with the original account layout
+    /// CHECK: Owned by the program and deserialized in the instruction
+    /// because it can't be deserialized as Registrar before the migration
+    #[account(mut, owner = crate::id())]
+    pub registrar: UncheckedAccount<'info>,
+
+    /// Funds the rent of the migrated Registrar
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_registrar_handler(ctx: Context<MigrateRegistrar>) -> Result<()> {
+    let registrar_info = ctx.accounts.registrar.to_account_info();
+
+    // Migrated Registrars are always larger than the original layout
+    require_eq!(
+        registrar_info.data_len(),
+        LegacyRegistrar::get_space(),
+        BonkPluginError::RegistrarAlreadyMigrated
+    );
+
+    let legacy_registrar = {
+        let data = registrar_info.try_borrow_data()?;
+
+        require!(
+            data.starts_with(&Registrar::DISCRIMINATOR),
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        LegacyRegistrar::deserialize(&mut &data[Registrar::DISCRIMINATOR.len()..])?
+    };
+
+    let registrar = legacy_registrar.migrate();
+    let new_space = Registrar::get_space(registrar.stake_pool_configs.len() as u8);
+
+    registrar_info.realloc(new_space, true)?;
+    registrar.try_serialize(&mut &mut registrar_info.try_borrow_mut_data()?[..])?;
+
+    let new_rent_minimum = Rent::get()?.minimum_balance(new_space);
+
+    if new_rent_minimum > registrar_info.lamports() {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: registrar_info.clone(),
+                },
+            ),
+            new_rent_minimum - registrar_info.lamports(),
+        )?;
+    }
+
+    Ok(())
+}
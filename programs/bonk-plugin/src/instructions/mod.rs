@@ -1,8 +1,32 @@
 pub use create_registrar::*;
 mod create_registrar;
 
+pub use configure_registrar::*;
+mod configure_registrar;
+
+pub use configure_stake_pool::*;
+mod configure_stake_pool;
+
+pub use resize_registrar::*;
+mod resize_registrar;
+
+pub use migrate_registrar::*;
+mod migrate_registrar;
+
 pub use create_voter_weight_record::*;
 mod create_voter_weight_record;
 
+pub use create_stake_deposit_record::*;
+mod create_stake_deposit_record;
+
+pub use close_stake_deposit_record::*;
+mod close_stake_deposit_record;
+
 pub use update_voter_weight_record::*;
 mod update_voter_weight_record;
+
+pub use create_max_voter_weight_record::*;
+mod create_max_voter_weight_record;
+
+pub use update_max_voter_weight_record::*;
+mod update_max_voter_weight_record;
@@ -0,0 +1,86 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token::Mint;
+use spl_governance::state::realm;
+
+use crate::{error::BonkPluginError, state::*};
+
+/// Resizes Registrar storing Stake Pool details for Bonk
+/// This instruction can only be executed if max_stake_pools is not lower than the number of currently configured stake_pool_configs
+/// Registrars created with the original account layout must be migrated using MigrateRegistrar first
+#[derive(Accounts)]
+pub struct ResizeRegistrar<'info> {
+    /// The Bonk plugin Registrar
+    /// CHECK: Owned by the program, validated by the Registrar PDA seeds and deserialized in the instruction
+    #[account(
+        mut,
+        seeds = [b"registrar".as_ref(), realm.key().as_ref(), governing_token_mint.key().as_ref()],
+        bump,
+        owner = crate::id(),
+    )]
+    pub registrar: UncheckedAccount<'info>,
+
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    pub governing_token_mint: Account<'info, Mint>,
+
+    /// realm_authority must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// Payer funds the increased account size and receives the rent refund when the account shrinks
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn resize_registrar_handler(ctx: Context<ResizeRegistrar>, max_stake_pools: u8) -> Result<()> {
+    let registrar_info = ctx.accounts.registrar.to_account_info();
+    let new_space = Registrar::get_space(max_stake_pools);
+
+    let registrar = Registrar::try_deserialize(&mut &registrar_info.try_borrow_data()?[..])?;
+
+    // Verify that realm_authority is the expected authority of the Realm
+    // and that the mint matches one of the realm mints too
+    let realm = realm::get_realm_data_for_governing_token_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.realm,
+        &registrar.governing_token_mint,
+    )?;
+
+    require!(
+        realm.authority == Some(ctx.accounts.realm_authority.key()),
+        BonkPluginError::InvalidRealmAuthority
+    );
+
+    require_gte!(
+        max_stake_pools as usize,
+        registrar.stake_pool_configs.len(),
+        BonkPluginError::InvalidResizeMaxStakePools
+    );
+
+    registrar_info.realloc(new_space, false)?;
+    registrar.try_serialize(&mut &mut registrar_info.try_borrow_mut_data()?[..])?;
+
+    let new_rent_minimum = Rent::get()?.minimum_balance(new_space);
+    let payer_info = ctx.accounts.payer.to_account_info();
+
+    if new_rent_minimum > registrar_info.lamports() {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer_info,
+                    to: registrar_info.clone(),
+                },
+            ),
+            new_rent_minimum - registrar_info.lamports(),
+        )?;
+    } else {
+        let rent_refund = registrar_info.lamports() - new_rent_minimum;
+        **registrar_info.try_borrow_mut_lamports()? -= rent_refund;
+        **payer_info.try_borrow_mut_lamports()? += rent_refund;
+    }
+
+    Ok(())
+}
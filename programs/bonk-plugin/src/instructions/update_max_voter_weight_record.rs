@@ -0,0 +1,141 @@
+use crate::error::BonkPluginError;
+use crate::state::*;
+use crate::utils::stake_pool::StakePool;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_pack::Pack;
+use gpl_shared::compose::resolve_input_max_voter_weight;
+use gpl_shared::generic_max_voter_weight::GenericMaxVoterWeight;
+use spl_governance::tools::spl_token::assert_is_valid_spl_token_account;
+use std::cmp::min;
+
+/// Updates MaxVoterWeightRecord based on the Stake Pools totals
+/// The max voter weight of a Stake Pool is the amount of tokens staked in the Stake Pool vault for StakeWeightMode::DepositAmount
+/// or the total weighted stake of the Stake Pool for StakeWeightMode::EffectiveStake, multiplied by the Stake Pool weight
+///
+/// All Stake Pools configured in the Registrar must be provided in remaining_accounts as (stake_pool, stake_pool_vault) pairs
+/// in the order of registrar.stake_pool_configs
+///
+/// The input max voter weight is added on top of the Stake Pools max voter weight
+/// It's the supply of the governing token mint, which bounds the TokenOwnerRecord deposits counted by UpdateVoterWeightRecord,
+/// or the max voter weight of the predecessor plugin when the Registrar has one
+///
+/// The instruction is permissionless and sets MaxVoterWeightRecord.max_voter_weight which is valid for the current slot only
+/// It must be executed inside the same transaction as the corresponding spl-gov instruction
+#[derive(Accounts)]
+pub struct UpdateMaxVoterWeightRecord<'info> {
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ BonkPluginError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ BonkPluginError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+
+    /// An account that is either of type MaxVoterWeightRecord of the previous plugin or the governing token Mint
+    /// The governing token Mint must be provided when the registrar doesn't include a predecessor
+    /// CHECK: Checked in the code depending on the registrar
+    pub input_max_voter_weight: Option<UncheckedAccount<'info>>,
+}
+
+pub fn update_max_voter_weight_record_handler(
+    ctx: Context<UpdateMaxVoterWeightRecord>,
+) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    require_eq!(
+        ctx.remaining_accounts.len(),
+        registrar.stake_pool_configs.len() * 2,
+        BonkPluginError::StakePoolAccountsCountMismatch
+    );
+
+    let mut stake_pool_max_voter_weight = 0u64;
+
+    for (stake_pool_config, stake_pool_accounts) in registrar
+        .stake_pool_configs
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(2))
+    {
+        let stake_pool_max_weight = resolve_stake_pool_max_weight(
+            registrar,
+            stake_pool_config,
+            &stake_pool_accounts[0],
+            &stake_pool_accounts[1],
+        )?;
+
+        stake_pool_max_voter_weight = stake_pool_max_voter_weight
+            .checked_add(stake_pool_max_weight)
+            .ok_or(BonkPluginError::VoteWeightOverflow)?;
+    }
+
+    let current_slot = Clock::get()?.slot;
+
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+
+    // The input max voter weight is required because the voter weight always includes the input voter weight
+    // which is either the TokenOwnerRecord deposit or the predecessor voter weight
+    let input_max_voter_weight_account = ctx
+        .accounts
+        .input_max_voter_weight
+        .as_ref()
+        .ok_or(BonkPluginError::MissingInputMaxVoterWeight)?
+        .to_account_info();
+
+    let clone_record = max_voter_weight_record.clone();
+    let input_max_voter_weight_record =
+        resolve_input_max_voter_weight(&input_max_voter_weight_account, &clone_record, registrar)?;
+
+    max_voter_weight_record.max_voter_weight = input_max_voter_weight_record
+        .get_max_voter_weight()
+        .checked_add(stake_pool_max_voter_weight)
+        .ok_or(BonkPluginError::VoteWeightOverflow)?;
+
+    // The Stake Pool totals are only valid as of the current slot
+    // and the input max voter weight can't be extended beyond its own expiry
+    max_voter_weight_record.max_voter_weight_expiry = input_max_voter_weight_record
+        .get_max_voter_weight_expiry()
+        .map_or(Some(current_slot), |previous_expiry| {
+            Some(min(previous_expiry, current_slot))
+        });
+
+    Ok(())
+}
+
+/// Resolves max voter weight of the given Stake Pool from its vault balance or total weighted stake
+fn resolve_stake_pool_max_weight(
+    registrar: &Registrar,
+    stake_pool_config: &StakePoolConfig,
+    stake_pool_info: &AccountInfo,
+    stake_pool_vault_info: &AccountInfo,
+) -> Result<u64> {
+    // The Stake Pool is validated by its address configured in the Registrar
+    require_keys_eq!(
+        *stake_pool_info.key,
+        stake_pool_config.stake_pool,
+        BonkPluginError::InvalidStakePool
+    );
+
+    let stake_pool = StakePool::deserialize_checked(stake_pool_info)?;
+
+    require_keys_eq!(
+        stake_pool.vault,
+        *stake_pool_vault_info.key,
+        BonkPluginError::InvalidStakePoolVault
+    );
+
+    let stake_pool_amount = match registrar.stake_weight_mode {
+        StakeWeightMode::DepositAmount => {
+            assert_is_valid_spl_token_account(stake_pool_vault_info)?;
+
+            let stake_pool_vault =
+                spl_token::state::Account::unpack(&stake_pool_vault_info.try_borrow_data()?)?;
+            stake_pool_vault.amount
+        }
+        StakeWeightMode::EffectiveStake => stake_pool.get_scaled_total_weighted_stake()?,
+    };
+
+    stake_pool_config.get_weight(stake_pool_amount)
+}
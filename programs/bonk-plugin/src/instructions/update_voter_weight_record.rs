@@ -1,4 +1,5 @@
 use crate::error::BonkPluginError;
+use crate::events::{StakeDepositWeight, VoterWeightRecordUpdated};
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_lang::Accounts;
@@ -109,10 +110,11 @@ pub fn update_voter_weight_record_handler(
 
     let input_voter_weight_record =
         resolve_input_voter_weight(&input_voter_weight_account, &clone_record, registrar)?;
+    let predecessor_voter_weight = input_voter_weight_record.get_voter_weight();
 
-    voter_weight = voter_weight
-        .checked_add(input_voter_weight_record.get_voter_weight())
-        .unwrap();
+    voter_weight = voter_weight.checked_add(predecessor_voter_weight).unwrap();
+
+    let mut stake_deposit_weights = Vec::with_capacity(ctx.remaining_accounts.len());
 
     let new_deposit_len =
         stake_deposit_record.new_deposit_len(stake_receipts_count, action_target, action);
@@ -131,7 +133,14 @@ pub fn update_voter_weight_record_handler(
             action_target,
         )?;
 
-        voter_weight = voter_weight.checked_add(vote_weight).unwrap();
+        voter_weight = voter_weight
+            .checked_add(vote_weight)
+            .ok_or(BonkPluginError::VoteWeightOverflow)?;
+
+        stake_deposit_weights.push(StakeDepositWeight {
+            stake_deposit_receipt: stake_deposit_receipt_info.key(),
+            weight: vote_weight,
+        });
     }
 
     if voter_weight_record.weight_action_target == Some(action_target)
@@ -158,8 +167,18 @@ pub fn update_voter_weight_record_handler(
     voter_weight_record.weight_action_target = Some(action_target);
 
     stake_deposit_record.weight_action_target = Some(action_target);
-    stake_deposit_record.previous_voter_weight = input_voter_weight_record.get_voter_weight();
+    stake_deposit_record.previous_voter_weight = predecessor_voter_weight;
     stake_deposit_record.weight_action = Some(action);
 
+    emit!(VoterWeightRecordUpdated {
+        voter_weight_record: voter_weight_record.key(),
+        governing_token_owner,
+        action,
+        action_target,
+        stake_deposit_weights,
+        predecessor_voter_weight,
+        voter_weight: voter_weight_record.voter_weight,
+    });
+
     Ok(())
 }
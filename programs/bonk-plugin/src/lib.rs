@@ -2,14 +2,17 @@ use anchor_lang::{prelude::*, solana_program::pubkey};
 
 mod instructions;
 use instructions::*;
-use state::VoterWeightAction;
+use state::{StakeWeightMode, VoterWeightAction};
 
 pub mod error;
+pub mod events;
 pub mod state;
 pub mod utils;
 
 declare_id!("7yJT49ajgYyuhWYzQMzwEt9u9Zbbt7r8Ft2wq1bhhfyy");
 
+/// The canonical deployment of spl-token-staking
+/// Registrars can be configured with any other instance of the program
 #[constant]
 pub const SPL_TOKEN_STAKING_PROGRAM_ID: Pubkey =
     pubkey!("STAKEkKzbdeKkqzKpLkNQD3SUuLgshDKCD7U8duxAbB");
@@ -18,9 +21,43 @@ pub const SPL_TOKEN_STAKING_PROGRAM_ID: Pubkey =
 pub mod bonk_plugin {
     use super::*;
 
-    pub fn create_registrar(ctx: Context<CreateRegistrar>) -> Result<()> {
+    pub fn create_registrar(
+        ctx: Context<CreateRegistrar>,
+        stake_weight_mode: StakeWeightMode,
+        max_stake_pools: u8,
+    ) -> Result<()> {
+        log_version();
+        create_registrar_handler(ctx, stake_weight_mode, max_stake_pools)
+    }
+
+    pub fn configure_registrar(
+        ctx: Context<ConfigureRegistrar>,
+        stake_weight_mode: StakeWeightMode,
+        allow_voter_weight_record_close: bool,
+        lockup_time_decay: bool,
+    ) -> Result<()> {
+        log_version();
+        configure_registrar_handler(
+            ctx,
+            stake_weight_mode,
+            allow_voter_weight_record_close,
+            lockup_time_decay,
+        )
+    }
+
+    pub fn configure_stake_pool(ctx: Context<ConfigureStakePool>, weight: u64) -> Result<()> {
+        log_version();
+        configure_stake_pool_handler(ctx, weight)
+    }
+
+    pub fn resize_registrar(ctx: Context<ResizeRegistrar>, max_stake_pools: u8) -> Result<()> {
+        log_version();
+        resize_registrar_handler(ctx, max_stake_pools)
+    }
+
+    pub fn migrate_registrar(ctx: Context<MigrateRegistrar>) -> Result<()> {
         log_version();
-        create_registrar_handler(ctx)
+        migrate_registrar_handler(ctx)
     }
 
     pub fn create_voter_weight_record(
@@ -31,6 +68,19 @@ pub mod bonk_plugin {
         create_voter_weight_record_handler(ctx, governing_token_owner)
     }
 
+    pub fn create_stake_deposit_record(ctx: Context<CreateStakeDepositRecord>) -> Result<()> {
+        log_version();
+        create_stake_deposit_record_handler(ctx)
+    }
+
+    pub fn close_stake_deposit_record(
+        ctx: Context<CloseStakeDepositRecord>,
+        close_voter_weight_record: bool,
+    ) -> Result<()> {
+        log_version();
+        close_stake_deposit_record_handler(ctx, close_voter_weight_record)
+    }
+
     pub fn update_voter_weight_record(
         ctx: Context<UpdateVoterWeightRecord>,
         stake_receipts_count: u8,
@@ -40,6 +90,16 @@ pub mod bonk_plugin {
         log_version();
         update_voter_weight_record_handler(ctx, stake_receipts_count, action_target, action)
     }
+
+    pub fn create_max_voter_weight_record(ctx: Context<CreateMaxVoterWeightRecord>) -> Result<()> {
+        log_version();
+        create_max_voter_weight_record_handler(ctx)
+    }
+
+    pub fn update_max_voter_weight_record(ctx: Context<UpdateMaxVoterWeightRecord>) -> Result<()> {
+        log_version();
+        update_max_voter_weight_record_handler(ctx)
+    }
 }
 
 fn log_version() {
@@ -6,3 +6,9 @@ mod voter_weight_record;
 
 pub use stake_deposit_record::*;
 mod stake_deposit_record;
+
+pub use max_voter_weight_record::*;
+mod max_voter_weight_record;
+
+pub use stake_pool_config::*;
+mod stake_pool_config;
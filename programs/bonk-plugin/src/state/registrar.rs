@@ -2,18 +2,83 @@ use gpl_shared::compose::RegistrarBase;
 use {
     super::VoterWeightAction,
     crate::{
-        error::BonkPluginError, id, state::VoterWeightRecord,
-        utils::stake_deposit_receipt::StakeDepositReceipt,
+        error::BonkPluginError,
+        id,
+        state::{StakePoolConfig, VoterWeightRecord},
+        utils::{
+            anchor::DISCRIMINATOR_SIZE, lockup_decay::get_lockup_decayed_weight,
+            stake_deposit_receipt::StakeDepositReceipt,
+        },
+        SPL_TOKEN_STAKING_PROGRAM_ID,
     },
     anchor_lang::prelude::*,
-    solana_program::pubkey::Pubkey,
+    solana_program::pubkey::{Pubkey, PUBKEY_BYTES},
     spl_governance::state::{governance, proposal, token_owner_record},
 };
 
+/// Defines how the voting power of a stake deposit is resolved
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum StakeWeightMode {
+    /// The raw amount of tokens deposited into the stake pool
+    DepositAmount,
+
+    /// The effective stake weighted by the lockup duration as computed by the staking program
+    /// It's scaled down by the stake pool scale factor to the token amount units
+    EffectiveStake,
+}
+
 /// Registrar which stores Token Voting configuration for the given Realm
 #[account]
-#[derive(Debug, PartialEq, InitSpace)]
+#[derive(Debug, PartialEq)]
 pub struct Registrar {
+    pub governance_program_id: Pubkey,
+    pub realm: Pubkey,
+    pub realm_authority: Pubkey,
+    pub governing_token_mint: Pubkey,
+    /// The Stake Pool the Registrar was created with
+    /// It's retained for compatibility with the original account layout and stake_pool_configs is used for voting
+    pub stake_pool: Pubkey,
+    pub previous_voter_weight_plugin_program_id: Option<Pubkey>,
+    pub stake_weight_mode: StakeWeightMode,
+    /// Allows voters to close their VoterWeightRecord together with StakeDepositRecord once the weight expired
+    pub allow_voter_weight_record_close: bool,
+    /// Decays the weight of stake deposits with their remaining lockup duration relative to the Stake Pool max duration
+    pub lockup_time_decay: bool,
+    pub reserved: [u8; 5],
+    /// Stake Pools accepted for voting with their weights
+    pub stake_pool_configs: Vec<StakePoolConfig>,
+    /// spl-token-staking program instance the Stake Pools belong to
+    /// Registrars created with the original account layout have it set by MigrateRegistrar
+    pub staking_program_id: Pubkey,
+}
+
+impl Registrar {
+    pub fn get_space(max_stake_pools: u8) -> usize {
+        DISCRIMINATOR_SIZE
+            + PUBKEY_BYTES * 5
+            + 1
+            + PUBKEY_BYTES
+            + 1
+            + 1
+            + 1
+            + 5
+            + 4
+            + max_stake_pools as usize * (PUBKEY_BYTES + 8 + 8)
+            + PUBKEY_BYTES
+    }
+
+    pub fn get_stake_pool_config(&self, stake_pool: &Pubkey) -> Result<&StakePoolConfig> {
+        self.stake_pool_configs
+            .iter()
+            .find(|spc| spc.stake_pool == *stake_pool)
+            .ok_or_else(|| BonkPluginError::InvalidStakePool.into())
+    }
+}
+
+/// Registrar with the original account layout which only stored a single Stake Pool
+/// It shares the Registrar discriminator and it's only used to migrate the Registrar with MigrateRegistrar
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq)]
+pub struct LegacyRegistrar {
     pub governance_program_id: Pubkey,
     pub realm: Pubkey,
     pub realm_authority: Pubkey,
@@ -23,6 +88,38 @@ pub struct Registrar {
     pub reserved: [u8; 8],
 }
 
+impl LegacyRegistrar {
+    /// Registrars with the original layout were always allocated for Some previous_voter_weight_plugin_program_id
+    pub fn get_space() -> usize {
+        DISCRIMINATOR_SIZE + PUBKEY_BYTES * 5 + 1 + PUBKEY_BYTES + 8
+    }
+
+    /// Returns Registrar with the legacy Stake Pool configured with weight 1 for the canonical spl-token-staking program
+    /// The voter weight is the raw deposit amount as it was granted by the original program
+    pub fn migrate(self) -> Registrar {
+        Registrar {
+            governance_program_id: self.governance_program_id,
+            realm: self.realm,
+            realm_authority: self.realm_authority,
+            governing_token_mint: self.governing_token_mint,
+            stake_pool: self.stake_pool,
+            previous_voter_weight_plugin_program_id: self.previous_voter_weight_plugin_program_id,
+            stake_weight_mode: StakeWeightMode::DepositAmount,
+            allow_voter_weight_record_close: false,
+            lockup_time_decay: false,
+            reserved: [0; 5],
+            // The max lockup duration of the legacy Stake Pool is not known and it's captured
+            // once the Stake Pool is configured using ConfigureStakePool
+            stake_pool_configs: vec![StakePoolConfig {
+                stake_pool: self.stake_pool,
+                weight: 1,
+                max_lockup_duration: 0,
+            }],
+            staking_program_id: SPL_TOKEN_STAKING_PROGRAM_ID,
+        }
+    }
+}
+
 impl<'a> RegistrarBase<'a> for Registrar {
     fn get_realm(&'a self) -> &'a Pubkey {
         &self.realm
@@ -101,8 +198,10 @@ pub fn resolve_stake_deposit_weight(
         BonkPluginError::MaximumDepositsReached
     );
 
-    let stake_deposit_receipt: StakeDepositReceipt =
-        StakeDepositReceipt::deserialize_checked(stake_deposit_receipt_info)?;
+    let stake_deposit_receipt: StakeDepositReceipt = StakeDepositReceipt::deserialize_checked(
+        stake_deposit_receipt_info,
+        &registrar.staking_program_id,
+    )?;
 
     let stake_deposit_receipt_key = stake_deposit_receipt_info.key();
 
@@ -113,12 +212,8 @@ pub fn resolve_stake_deposit_weight(
         BonkPluginError::VoterDoesNotOwnDepositReceipt
     );
 
-    // Stake Pool of the deposit receipt must match the Stake pool in Registrar
-    require_keys_eq!(
-        stake_deposit_receipt.stake_pool,
-        registrar.stake_pool,
-        BonkPluginError::InvalidStakePool
-    );
+    // Stake Pool of the deposit receipt must be one of the Stake Pools in Registrar
+    let stake_pool_config = registrar.get_stake_pool_config(&stake_deposit_receipt.stake_pool)?;
 
     // Ensure the same receipt was not provided more than once
     if unique_stake_deposit_receipts.contains(&stake_deposit_receipt_key) {
@@ -161,7 +256,33 @@ pub fn resolve_stake_deposit_weight(
         }
     }
 
-    Ok(stake_deposit_receipt.deposit_amount)
+    let stake_deposit_amount = match registrar.stake_weight_mode {
+        StakeWeightMode::DepositAmount => stake_deposit_receipt.deposit_amount,
+        StakeWeightMode::EffectiveStake => stake_deposit_receipt.get_scaled_effective_stake()?,
+    };
+
+    let stake_deposit_amount = if registrar.lockup_time_decay {
+        // Without the max lockup duration the decay can't be applied and the deposit would be granted its full weight
+        require_gt!(
+            stake_pool_config.max_lockup_duration,
+            0,
+            BonkPluginError::StakePoolMaxLockupDurationNotSet
+        );
+
+        get_lockup_decayed_weight(
+            stake_deposit_amount,
+            stake_deposit_end_time
+                .checked_sub(current_timestamp)
+                .unwrap()
+                .try_into()
+                .unwrap(),
+            stake_pool_config.max_lockup_duration,
+        )
+    } else {
+        stake_deposit_amount
+    };
+
+    stake_pool_config.get_weight(stake_deposit_amount)
 }
 
 pub fn resolve_proposal_end_time(
@@ -188,3 +309,63 @@ pub fn resolve_proposal_end_time(
 
     Ok(proposal_end_time)
 }
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = Registrar::get_space(3);
+
+        let registrar = Registrar {
+            governance_program_id: Pubkey::default(),
+            realm: Pubkey::default(),
+            realm_authority: Pubkey::default(),
+            governing_token_mint: Pubkey::default(),
+            stake_pool: Pubkey::default(),
+            previous_voter_weight_plugin_program_id: Pubkey::default().into(),
+            stake_weight_mode: StakeWeightMode::EffectiveStake,
+            allow_voter_weight_record_close: true,
+            lockup_time_decay: true,
+            reserved: [0; 5],
+            stake_pool_configs: vec![
+                StakePoolConfig::default(),
+                StakePoolConfig::default(),
+                StakePoolConfig::default(),
+            ],
+            staking_program_id: Pubkey::default(),
+        };
+
+        // Act
+        let actual_space = DISCRIMINATOR_SIZE + registrar.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+
+    #[test]
+    fn test_get_legacy_space() {
+        // Arrange
+        let expected_space = LegacyRegistrar::get_space();
+
+        let legacy_registrar = LegacyRegistrar {
+            governance_program_id: Pubkey::default(),
+            realm: Pubkey::default(),
+            realm_authority: Pubkey::default(),
+            governing_token_mint: Pubkey::default(),
+            stake_pool: Pubkey::default(),
+            previous_voter_weight_plugin_program_id: Pubkey::default().into(),
+            reserved: [0; 8],
+        };
+
+        // Act
+        let actual_space = DISCRIMINATOR_SIZE + legacy_registrar.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+        assert_eq!(actual_space, 209);
+    }
+}
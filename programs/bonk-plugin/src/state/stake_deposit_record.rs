@@ -15,6 +15,20 @@ pub struct StakeDepositRecord {
 impl StakeDepositRecord {
     pub const FIXED_LEN: usize = 8 + 33 + 4 + 1 + 1 + 8 + 2;
 
+    /// The number of deposits the account is initially allocated for
+    pub const INIT_DEPOSITS_LEN: u8 = 8;
+
+    pub const INIT_SPACE: usize =
+        StakeDepositRecord::FIXED_LEN + StakeDepositRecord::INIT_DEPOSITS_LEN as usize * 32;
+
+    pub fn init(&mut self, bump: u8) {
+        self.weight_action_target = None;
+        self.weight_action = None;
+        self.deposits_len = StakeDepositRecord::INIT_DEPOSITS_LEN;
+        self.bump = bump;
+        self.previous_voter_weight = 0;
+    }
+
     pub fn realloc_bytes(
         &self,
         new_receipts_len: u8,
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::error::BonkPluginError;
+
+/// Configuration of a Stake Pool used for governance power
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct StakePoolConfig {
+    /// The Stake Pool used for governance
+    pub stake_pool: Pubkey,
+
+    /// Governance power weight of the Stake Pool
+    /// Each staked token in the pool has governance power = 1 * weight
+    /// Ex: a pool for a longer lockup tier can be given weight 2 to double the governance power of its deposits
+    pub weight: u64,
+
+    /// Maximum lockup duration of the Stake Pool in seconds captured when the Stake Pool is configured
+    /// It's used to decay the weight of deposits when Registrar.lockup_time_decay is enabled
+    /// Stake Pools migrated from the original Registrar layout have it set to 0 until they are configured using ConfigureStakePool
+    /// and lockup time decay can't be applied to them before
+    pub max_lockup_duration: u64,
+}
+
+impl StakePoolConfig {
+    pub fn get_weight(&self, amount: u64) -> Result<u64> {
+        amount
+            .checked_mul(self.weight)
+            .ok_or_else(|| BonkPluginError::VoteWeightOverflow.into())
+    }
+}
@@ -0,0 +1,94 @@
+/// Returns the weight of the given amount decayed linearly with the remaining lockup duration
+/// The full amount is only granted when the remaining lockup duration is at least max_lockup_duration
+/// and the weight decays to 0 as the lockup approaches its end
+///
+/// If max_lockup_duration is 0 then the decay is not applied and the full amount is returned
+pub fn get_lockup_decayed_weight(
+    amount: u64,
+    remaining_lockup_duration: u64,
+    max_lockup_duration: u64,
+) -> u64 {
+    if max_lockup_duration == 0 {
+        return amount;
+    }
+
+    let remaining_lockup_duration = remaining_lockup_duration.min(max_lockup_duration);
+
+    let decayed_weight = (amount as u128)
+        .checked_mul(remaining_lockup_duration as u128)
+        .unwrap()
+        .checked_div(max_lockup_duration as u128)
+        .unwrap();
+
+    // The decayed weight can't exceed amount and hence it always fits u64
+    decayed_weight as u64
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_get_lockup_decayed_weight_with_max_lockup_duration() {
+        // Act
+        let weight = get_lockup_decayed_weight(1000, 100, 100);
+
+        // Assert
+        assert_eq!(weight, 1000);
+    }
+
+    #[test]
+    fn test_get_lockup_decayed_weight_with_lockup_duration_above_max() {
+        // Act
+        let weight = get_lockup_decayed_weight(1000, 500, 100);
+
+        // Assert
+        assert_eq!(weight, 1000);
+    }
+
+    #[test]
+    fn test_get_lockup_decayed_weight_with_half_lockup_duration() {
+        // Act
+        let weight = get_lockup_decayed_weight(1000, 50, 100);
+
+        // Assert
+        assert_eq!(weight, 500);
+    }
+
+    #[test]
+    fn test_get_lockup_decayed_weight_rounds_down() {
+        // Act
+        let weight = get_lockup_decayed_weight(10, 1, 3);
+
+        // Assert
+        assert_eq!(weight, 3);
+    }
+
+    #[test]
+    fn test_get_lockup_decayed_weight_with_expired_lockup() {
+        // Act
+        let weight = get_lockup_decayed_weight(1000, 0, 100);
+
+        // Assert
+        assert_eq!(weight, 0);
+    }
+
+    #[test]
+    fn test_get_lockup_decayed_weight_with_zero_max_lockup_duration() {
+        // Act
+        let weight = get_lockup_decayed_weight(1000, 50, 0);
+
+        // Assert
+        assert_eq!(weight, 1000);
+    }
+
+    #[test]
+    fn test_get_lockup_decayed_weight_with_max_amount() {
+        // Act
+        let weight = get_lockup_decayed_weight(u64::MAX, u64::MAX - 1, u64::MAX);
+
+        // Assert
+        assert_eq!(weight, u64::MAX - 1);
+    }
+}
@@ -1,3 +1,4 @@
 pub mod anchor;
+pub mod lockup_decay;
 pub mod stake_deposit_receipt;
 pub mod stake_pool;
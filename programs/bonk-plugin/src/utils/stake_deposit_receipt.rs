@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::SPL_TOKEN_STAKING_PROGRAM_ID;
+use crate::{error::BonkPluginError, utils::stake_pool::SCALE_FACTOR_BASE};
 
 #[repr(C)]
 #[derive(AnchorDeserialize, Debug)]
@@ -28,8 +28,11 @@ pub struct StakeDepositReceipt {
 impl StakeDepositReceipt {
     pub const ACCOUNT_DISCRIMINATOR: [u8; 8] = [210, 98, 254, 196, 151, 68, 235, 0];
 
-    pub fn deserialize_checked(stake_deposit_receipt_account_info: &AccountInfo) -> Result<Self> {
-        if stake_deposit_receipt_account_info.owner != &SPL_TOKEN_STAKING_PROGRAM_ID {
+    pub fn deserialize_checked(
+        stake_deposit_receipt_account_info: &AccountInfo,
+        staking_program_id: &Pubkey,
+    ) -> Result<Self> {
+        if stake_deposit_receipt_account_info.owner != staking_program_id {
             return Err(anchor_lang::error!(
                 anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
             )
@@ -50,4 +53,17 @@ impl StakeDepositReceipt {
 
         Ok(stake_deposit_receipt)
     }
+
+    /// Returns the effective stake scaled down by the stake pool scale factor
+    /// The effective stake is stored in terms of 1 / SCALE_FACTOR_BASE and scaling it down
+    /// yields the deposit amount multiplied by the lockup weight in the token amount units
+    pub fn get_scaled_effective_stake(&self) -> Result<u64> {
+        let scaled_effective_stake = self
+            .effective_stake
+            .checked_div(SCALE_FACTOR_BASE as u128)
+            .unwrap();
+
+        u64::try_from(scaled_effective_stake)
+            .map_err(|_| BonkPluginError::VoteWeightOverflow.into())
+    }
 }
@@ -1,5 +1,10 @@
 use anchor_lang::prelude::*;
 
+use crate::error::BonkPluginError;
+
+/// The scale factor used by the staking program for StakePool weights and StakeDepositReceipt effective stake
+pub const SCALE_FACTOR_BASE: u64 = 1_000_000_000;
+
 #[repr(C)]
 #[derive(AnchorDeserialize, Debug)]
 pub struct StakePool {
@@ -74,4 +79,15 @@ impl StakePool {
 
         Ok(stake_pool)
     }
+
+    /// Returns the total weighted stake scaled down by the stake pool scale factor
+    pub fn get_scaled_total_weighted_stake(&self) -> Result<u64> {
+        let scaled_total_weighted_stake = self
+            .total_weighted_stake
+            .checked_div(SCALE_FACTOR_BASE as u128)
+            .unwrap();
+
+        u64::try_from(scaled_total_weighted_stake)
+            .map_err(|_| BonkPluginError::VoteWeightOverflow.into())
+    }
 }
@@ -0,0 +1,405 @@
+use crate::program_test::bonk_plugin_test::{get_stake_deposit_record_address, BonkPluginTest};
+use gpl_bonk_plugin::{
+    error::BonkPluginError,
+    state::{StakeWeightMode, VoterWeightAction},
+};
+use program_test::program_test_bench::{airdrop, WalletCookie};
+use program_test::spl_token_staking_test::find_stake_receipt_key;
+use program_test::{spl_token_staking_test::SplTokenStakingCookie, tools::*};
+use solana_program_test::*;
+use solana_sdk::native_token::sol_to_lamports;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_close_stake_deposit_record() -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let voter_cookie = bonk_plugin_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = bonk_plugin_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let beneficiary_cookie = bonk_plugin_test.bench.with_wallet().await;
+
+    bonk_plugin_test.bench.advance_clock().await;
+
+    // Act
+    bonk_plugin_test
+        .close_stake_deposit_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_cookie.signer,
+            &beneficiary_cookie.address,
+            false,
+        )
+        .await?;
+
+    // Assert
+    let stake_deposit_record_account = bonk_plugin_test
+        .bench
+        .get_account(&get_stake_deposit_record_address(
+            &voter_weight_record_cookie.address,
+        ))
+        .await;
+
+    assert!(stake_deposit_record_account.is_none());
+
+    let voter_weight_record = bonk_plugin_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record, voter_weight_record_cookie.account);
+
+    let beneficiary_account = bonk_plugin_test
+        .bench
+        .get_account(&beneficiary_cookie.address)
+        .await
+        .unwrap();
+
+    assert!(beneficiary_account.lamports > bonk_plugin_test.bench.rent.minimum_balance(0));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_stake_deposit_record_with_voter_weight_record() -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    bonk_plugin_test
+        .configure_registrar(
+            &realm_cookie,
+            &mut registrar_cookie,
+            StakeWeightMode::DepositAmount,
+            true,
+            false,
+            None,
+        )
+        .await?;
+
+    let voter_cookie = bonk_plugin_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = bonk_plugin_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let beneficiary_cookie = bonk_plugin_test.bench.with_wallet().await;
+
+    bonk_plugin_test.bench.advance_clock().await;
+
+    // Act
+    bonk_plugin_test
+        .close_stake_deposit_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_cookie.signer,
+            &beneficiary_cookie.address,
+            true,
+        )
+        .await?;
+
+    // Assert
+    let stake_deposit_record_account = bonk_plugin_test
+        .bench
+        .get_account(&get_stake_deposit_record_address(
+            &voter_weight_record_cookie.address,
+        ))
+        .await;
+
+    assert!(stake_deposit_record_account.is_none());
+
+    let voter_weight_record_account = bonk_plugin_test
+        .bench
+        .get_account(&voter_weight_record_cookie.address)
+        .await;
+
+    assert!(voter_weight_record_account.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_stake_deposit_record_with_voter_weight_record_close_not_allowed_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let voter_cookie = bonk_plugin_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = bonk_plugin_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    bonk_plugin_test.bench.advance_clock().await;
+
+    // Act
+    let err = bonk_plugin_test
+        .close_stake_deposit_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_cookie.signer,
+            &voter_cookie.address,
+            true,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_bonks_plugin_err(err, BonkPluginError::VoterWeightRecordCloseNotAllowed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_stake_deposit_record_with_invalid_voter_authority_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let voter_cookie = bonk_plugin_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = bonk_plugin_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    let voter_authority = Keypair::new();
+
+    bonk_plugin_test.bench.advance_clock().await;
+
+    // Act
+    let err = bonk_plugin_test
+        .close_stake_deposit_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_authority,
+            &voter_authority.pubkey(),
+            false,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_bonks_plugin_err(err, BonkPluginError::InvalidTokenOwnerForVoterWeightRecord);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_stake_deposit_record_after_close() -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let voter_cookie = bonk_plugin_test.bench.with_wallet().await;
+
+    let voter_weight_record_cookie = bonk_plugin_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+
+    bonk_plugin_test.bench.advance_clock().await;
+
+    bonk_plugin_test
+        .close_stake_deposit_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &voter_cookie.signer,
+            &voter_cookie.address,
+            false,
+        )
+        .await?;
+
+    // Act
+    bonk_plugin_test
+        .with_stake_deposit_record(&registrar_cookie, &voter_weight_record_cookie)
+        .await?;
+
+    // Assert
+    let stake_deposit_record_account = bonk_plugin_test
+        .bench
+        .get_account(&get_stake_deposit_record_address(
+            &voter_weight_record_cookie.address,
+        ))
+        .await;
+
+    assert!(stake_deposit_record_account.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_after_stake_deposit_record_recreated(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let depositor = Keypair::new();
+    airdrop(
+        &mut bonk_plugin_test.bench.context.borrow_mut(),
+        &depositor.pubkey(),
+        sol_to_lamports(10.0),
+    )
+    .await?;
+    let token_account_cookie = bonk_plugin_test
+        .bench
+        .with_tokens(
+            &realm_cookie.community_mint_cookie,
+            &depositor.pubkey(),
+            100,
+        )
+        .await?;
+
+    let stake_deposit_receipt = find_stake_receipt_key(
+        depositor.pubkey(),
+        stake_pool_pubkey,
+        0,
+        spl_token_staking_cookie.program_id,
+    );
+    spl_token_staking_cookie
+        .deposit_into_stake_pool(
+            &depositor,
+            &stake_pool_pubkey,
+            &stake_deposit_receipt,
+            &token_account_cookie.address,
+            &[],
+        )
+        .await?;
+
+    let voter_cookie = WalletCookie {
+        address: depositor.pubkey(),
+        signer: clone_keypair(&depositor),
+    };
+    let voter_weight_record_cookie = bonk_plugin_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+    let token_owner_record_cookie = bonk_plugin_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    // The governance account is only used for CastVote and the Realm is owned by spl-governance
+    let update_voter_weight_record_ix = bonk_plugin_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &token_owner_record_cookie.address,
+            &token_owner_record_cookie,
+            realm_cookie.address,
+            VoterWeightAction::CreateGovernance,
+            None,
+            &depositor,
+            realm_cookie.address,
+            &Some(vec![stake_deposit_receipt]),
+        )
+        .await?;
+
+    bonk_plugin_test
+        .bench
+        .process_transaction(
+            &[update_voter_weight_record_ix.clone()],
+            Some(&[&depositor]),
+        )
+        .await?;
+
+    bonk_plugin_test.bench.advance_clock().await;
+
+    bonk_plugin_test
+        .close_stake_deposit_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &depositor,
+            &depositor.pubkey(),
+            false,
+        )
+        .await?;
+
+    let create_stake_deposit_record_ix = bonk_plugin_test
+        .create_stake_deposit_record_ix(&registrar_cookie, &voter_weight_record_cookie);
+
+    // Act
+    bonk_plugin_test
+        .bench
+        .process_transaction(
+            &[
+                create_stake_deposit_record_ix,
+                update_voter_weight_record_ix,
+            ],
+            Some(&[&depositor]),
+        )
+        .await?;
+
+    // Assert
+    let voter_weight_record = bonk_plugin_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 100);
+    assert_eq!(
+        voter_weight_record.weight_action,
+        Some(VoterWeightAction::CreateGovernance.into())
+    );
+    assert_eq!(
+        voter_weight_record.weight_action_target,
+        Some(realm_cookie.address)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,214 @@
+mod program_test;
+
+use anchor_lang::prelude::{ErrorCode, Pubkey};
+use gpl_bonk_plugin::{error::BonkPluginError, state::StakeWeightMode};
+use program_test::{
+    bonk_plugin_test::BonkPluginTest, spl_token_staking_test::SplTokenStakingCookie,
+};
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, transport::TransportError};
+
+use program_test::tools::{assert_anchor_err, assert_bonks_plugin_err};
+
+#[tokio::test]
+async fn test_configure_registrar() -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let previous_voter_weight_plugin_program_id = Pubkey::new_unique();
+
+    // Act
+    bonk_plugin_test
+        .configure_registrar(
+            &realm_cookie,
+            &mut registrar_cookie,
+            StakeWeightMode::EffectiveStake,
+            true,
+            true,
+            Some(previous_voter_weight_plugin_program_id),
+        )
+        .await?;
+
+    // Assert
+    let registrar = bonk_plugin_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar, registrar_cookie.account);
+    assert_eq!(
+        registrar.previous_voter_weight_plugin_program_id,
+        Some(previous_voter_weight_plugin_program_id)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_registrar_with_invalid_realm_authority_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let mut realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    realm_cookie.realm_authority = Keypair::new();
+
+    // Act
+    let err = bonk_plugin_test
+        .configure_registrar(
+            &realm_cookie,
+            &mut registrar_cookie,
+            StakeWeightMode::DepositAmount,
+            false,
+            false,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_bonks_plugin_err(err, BonkPluginError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_registrar_with_realm_authority_must_sign_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    // Act
+    let err = bonk_plugin_test
+        .configure_registrar_using_ix(
+            &realm_cookie,
+            &mut registrar_cookie,
+            StakeWeightMode::DepositAmount,
+            false,
+            false,
+            None,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_registrar_with_invalid_realm_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let realm_cookie2 = bonk_plugin_test.governance.with_realm().await?;
+
+    // Act
+    let err = bonk_plugin_test
+        .configure_registrar_using_ix(
+            &realm_cookie,
+            &mut registrar_cookie,
+            StakeWeightMode::DepositAmount,
+            false,
+            false,
+            None,
+            |i| i.accounts[1].pubkey = realm_cookie2.address, // realm
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_bonks_plugin_err(err, BonkPluginError::InvalidRealmForRegistrar);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_registrar_with_lockup_time_decay_for_migrated_stake_pool_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    // The migrated Stake Pool has no max lockup duration until it's configured using ConfigureStakePool
+    bonk_plugin_test
+        .with_legacy_registrar_layout(&registrar_cookie)
+        .await;
+    bonk_plugin_test
+        .migrate_registrar(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = bonk_plugin_test
+        .configure_registrar(
+            &realm_cookie,
+            &mut registrar_cookie,
+            StakeWeightMode::DepositAmount,
+            false,
+            true,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_bonks_plugin_err(err, BonkPluginError::StakePoolMaxLockupDurationNotSet);
+
+    Ok(())
+}
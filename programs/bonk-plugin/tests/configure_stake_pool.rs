@@ -0,0 +1,262 @@
+mod program_test;
+
+use anchor_lang::prelude::ErrorCode;
+use gpl_bonk_plugin::error::BonkPluginError;
+use program_test::{
+    bonk_plugin_test::BonkPluginTest, spl_token_staking_test::SplTokenStakingCookie,
+};
+
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, transport::TransportError};
+
+use program_test::tools::{assert_anchor_err, assert_bonks_plugin_err};
+
+#[tokio::test]
+async fn test_configure_stake_pool() -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let stake_pool_pubkey2 = spl_token_staking_cookie
+        .with_stake_pool_using_nonce(&realm_cookie.community_mint_cookie.address, 1)
+        .await?;
+
+    // Act
+    bonk_plugin_test
+        .configure_stake_pool(&realm_cookie, &mut registrar_cookie, &stake_pool_pubkey2, 2)
+        .await?;
+
+    // Assert
+    let registrar = bonk_plugin_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar, registrar_cookie.account);
+    assert_eq!(registrar.stake_pool_configs.len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_stake_pool_with_existing_stake_pool() -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    // Act
+    bonk_plugin_test
+        .configure_stake_pool(&realm_cookie, &mut registrar_cookie, &stake_pool_pubkey, 5)
+        .await?;
+
+    // Assert
+    let registrar = bonk_plugin_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar, registrar_cookie.account);
+    assert_eq!(registrar.stake_pool_configs.len(), 1);
+    assert_eq!(registrar.stake_pool_configs[0].weight, 5);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_stake_pool_with_zero_weight_removes_stake_pool(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let stake_pool_pubkey2 = spl_token_staking_cookie
+        .with_stake_pool_using_nonce(&realm_cookie.community_mint_cookie.address, 1)
+        .await?;
+
+    bonk_plugin_test
+        .configure_stake_pool(&realm_cookie, &mut registrar_cookie, &stake_pool_pubkey2, 1)
+        .await?;
+
+    // Act
+    bonk_plugin_test
+        .configure_stake_pool(&realm_cookie, &mut registrar_cookie, &stake_pool_pubkey, 0)
+        .await?;
+
+    // Assert
+    let registrar = bonk_plugin_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar, registrar_cookie.account);
+    assert_eq!(registrar.stake_pool_configs.len(), 1);
+    assert_eq!(
+        registrar.stake_pool_configs[0].stake_pool,
+        stake_pool_pubkey2
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_stake_pool_with_zero_weight_for_not_configured_stake_pool_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let stake_pool_pubkey2 = spl_token_staking_cookie
+        .with_stake_pool_using_nonce(&realm_cookie.community_mint_cookie.address, 1)
+        .await?;
+
+    // Act
+    let err = bonk_plugin_test
+        .configure_stake_pool(&realm_cookie, &mut registrar_cookie, &stake_pool_pubkey2, 0)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_bonks_plugin_err(err, BonkPluginError::InvalidStakePool);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_stake_pool_with_stake_pool_for_different_mint_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let mint_cookie = bonk_plugin_test.bench.with_mint().await?;
+    let other_stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&mint_cookie.address)
+        .await?;
+
+    // Act
+    let err = bonk_plugin_test
+        .configure_stake_pool(
+            &realm_cookie,
+            &mut registrar_cookie,
+            &other_stake_pool_pubkey,
+            1,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_bonks_plugin_err(err, BonkPluginError::InvalidGoverningToken);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_stake_pool_with_invalid_realm_authority_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let mut realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    realm_cookie.realm_authority = Keypair::new();
+
+    // Act
+    let err = bonk_plugin_test
+        .configure_stake_pool(&realm_cookie, &mut registrar_cookie, &stake_pool_pubkey, 2)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_bonks_plugin_err(err, BonkPluginError::InvalidRealmAuthority);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_stake_pool_with_realm_authority_must_sign_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    // Act
+    let err = bonk_plugin_test
+        .configure_stake_pool_using_ix(
+            &realm_cookie,
+            &mut registrar_cookie,
+            &stake_pool_pubkey,
+            2,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
@@ -5,6 +5,7 @@ use program_test::{
     bonk_plugin_test::BonkPluginTest, spl_token_staking_test::SplTokenStakingCookie,
 };
 
+use gpl_bonk_plugin::state::StakeWeightMode;
 use solana_program::instruction::InstructionError;
 use solana_program_test::*;
 use solana_sdk::{signature::Keypair, transport::TransportError};
@@ -37,6 +38,38 @@ async fn test_create_registrar() -> Result<(), TransportError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_create_registrar_with_effective_stake_weight_mode() -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+
+    // Act
+    let registrar_cookie = bonk_plugin_test
+        .with_registrar_with_stake_weight_mode(
+            &realm_cookie,
+            &stake_pool_pubkey,
+            StakeWeightMode::EffectiveStake,
+        )
+        .await?;
+
+    // Assert
+    let registrar = bonk_plugin_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar, registrar_cookie.account);
+    assert_eq!(registrar.stake_weight_mode, StakeWeightMode::EffectiveStake);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_create_registrar_with_invalid_realm_authority_error() -> Result<(), TransportError> {
     // Arrange
@@ -81,7 +114,8 @@ async fn test_create_registrar_with_realm_authority_must_sign_error() -> Result<
         .with_registrar_using_ix(
             &realm_cookie,
             &stake_pool_pubkey,
-            |i| i.accounts[6].is_signer = false, // realm_authority
+            StakeWeightMode::DepositAmount,
+            |i| i.accounts[7].is_signer = false, // realm_authority
             Some(&[]),
         )
         .await
@@ -113,6 +147,7 @@ async fn test_create_registrar_with_invalid_spl_gov_program_id_error() -> Result
         .with_registrar_using_ix(
             &realm_cookie,
             &stake_pool_pubkey,
+            StakeWeightMode::DepositAmount,
             |i| i.accounts[1].pubkey = governance_program_id, //governance_program_id
             None,
         )
@@ -125,6 +160,40 @@ async fn test_create_registrar_with_invalid_spl_gov_program_id_error() -> Result
     Ok(())
 }
 
+#[tokio::test]
+async fn test_create_registrar_with_stake_pool_not_owned_by_staking_program_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+
+    // Try to use a different staking program id
+    let staking_program_id = bonk_plugin_test.program_id;
+
+    // Act
+    let err = bonk_plugin_test
+        .with_registrar_using_ix(
+            &realm_cookie,
+            &stake_pool_pubkey,
+            StakeWeightMode::DepositAmount,
+            |i| i.accounts[4].pubkey = staking_program_id, // staking_program_id
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::ConstraintOwner);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_create_registrar_with_invalid_realm_error() -> Result<(), TransportError> {
     // Arrange
@@ -142,6 +211,7 @@ async fn test_create_registrar_with_invalid_realm_error() -> Result<(), Transpor
         .with_registrar_using_ix(
             &realm_cookie,
             &stake_pool_pubkey,
+            StakeWeightMode::DepositAmount,
             |i| i.accounts[3].pubkey = Pubkey::new_unique(), // realm
             None,
         )
@@ -175,7 +245,8 @@ async fn test_create_registrar_with_invalid_governing_token_mint_error(
         .with_registrar_using_ix(
             &realm_cookie,
             &stake_pool_pubkey,
-            |i| i.accounts[5].pubkey = mint_cookie.address, // governing_token_mint
+            StakeWeightMode::DepositAmount,
+            |i| i.accounts[6].pubkey = mint_cookie.address, // governing_token_mint
             None,
         )
         .await
@@ -0,0 +1,110 @@
+mod program_test;
+
+use gpl_bonk_plugin::{
+    error::BonkPluginError,
+    state::{Registrar, StakePoolConfig, StakeWeightMode},
+    SPL_TOKEN_STAKING_PROGRAM_ID,
+};
+use program_test::{
+    bonk_plugin_test::BonkPluginTest, spl_token_staking_test::SplTokenStakingCookie,
+};
+
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+use program_test::tools::assert_bonks_plugin_err;
+
+#[tokio::test]
+async fn test_migrate_registrar() -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    bonk_plugin_test
+        .with_legacy_registrar_layout(&registrar_cookie)
+        .await;
+
+    let legacy_registrar_account = bonk_plugin_test
+        .bench
+        .get_account(&registrar_cookie.address)
+        .await
+        .unwrap();
+
+    assert_eq!(legacy_registrar_account.data.len(), 209);
+
+    // Act
+    bonk_plugin_test
+        .migrate_registrar(&registrar_cookie)
+        .await?;
+
+    // Assert
+    let registrar_account = bonk_plugin_test
+        .bench
+        .get_account(&registrar_cookie.address)
+        .await
+        .unwrap();
+
+    assert_eq!(registrar_account.data.len(), Registrar::get_space(1));
+
+    let registrar = bonk_plugin_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.realm, realm_cookie.address);
+    assert_eq!(
+        registrar.realm_authority,
+        registrar_cookie.account.realm_authority
+    );
+    assert_eq!(registrar.stake_pool, stake_pool_pubkey);
+    assert_eq!(registrar.previous_voter_weight_plugin_program_id, None);
+    assert_eq!(registrar.stake_weight_mode, StakeWeightMode::DepositAmount);
+    assert_eq!(registrar.staking_program_id, SPL_TOKEN_STAKING_PROGRAM_ID);
+    assert_eq!(
+        registrar.stake_pool_configs,
+        vec![StakePoolConfig {
+            stake_pool: stake_pool_pubkey,
+            weight: 1,
+            max_lockup_duration: 0,
+        }]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_migrate_registrar_with_already_migrated_registrar_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    // Act
+    let err = bonk_plugin_test
+        .migrate_registrar(&registrar_cookie)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_bonks_plugin_err(err, BonkPluginError::RegistrarAlreadyMigrated);
+
+    Ok(())
+}
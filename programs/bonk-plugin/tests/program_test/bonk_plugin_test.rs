@@ -17,7 +17,9 @@ use crate::program_test::program_test_bench::WalletCookie;
 use crate::program_test::tools::NopOverride;
 
 use super::governance_test::TokenOwnerRecordCookie;
-use super::spl_token_staking_test::SplTokenStakingCookie;
+use super::spl_token_staking_test::{
+    find_vault_key, SplTokenStakingCookie, STAKE_POOL_MAX_DURATION,
+};
 
 #[derive(Debug, PartialEq)]
 pub struct RegistrarCookie {
@@ -25,7 +27,7 @@ pub struct RegistrarCookie {
     pub account: Registrar,
 
     pub realm_authority: Keypair,
-    pub max_governance_programs: u8,
+    pub max_stake_pools: u8,
 }
 
 #[allow(dead_code)]
@@ -34,11 +36,29 @@ pub struct VoterWeightRecordCookie {
     pub account: VoterWeightRecord,
 }
 
+#[allow(dead_code)]
+pub struct MaxVoterWeightRecordCookie {
+    pub address: Pubkey,
+    pub account: MaxVoterWeightRecord,
+}
+
 #[allow(dead_code)]
 pub struct GovernanceProgramCookie {
     pub program_id: Pubkey,
 }
 
+#[allow(dead_code)]
+pub fn get_stake_deposit_record_address(voter_weight_record: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"stake-deposit-record".as_ref(),
+            voter_weight_record.as_ref(),
+        ],
+        &gpl_bonk_plugin::id(),
+    )
+    .0
+}
+
 #[allow(dead_code)]
 pub struct BonkPluginTest {
     pub program_id: Pubkey,
@@ -91,8 +111,31 @@ impl BonkPluginTest {
         realm_cookie: &RealmCookie,
         stake_pool_key: &Pubkey,
     ) -> Result<RegistrarCookie, BanksClientError> {
-        self.with_registrar_using_ix(realm_cookie, stake_pool_key, NopOverride, None)
-            .await
+        self.with_registrar_using_ix(
+            realm_cookie,
+            stake_pool_key,
+            StakeWeightMode::DepositAmount,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_registrar_with_stake_weight_mode(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        stake_pool_key: &Pubkey,
+        stake_weight_mode: StakeWeightMode,
+    ) -> Result<RegistrarCookie, BanksClientError> {
+        self.with_registrar_using_ix(
+            realm_cookie,
+            stake_pool_key,
+            stake_weight_mode,
+            NopOverride,
+            None,
+        )
+        .await
     }
 
     #[allow(dead_code)]
@@ -100,22 +143,27 @@ impl BonkPluginTest {
         &mut self,
         realm_cookie: &RealmCookie,
         stake_pool_key: &Pubkey,
+        stake_weight_mode: StakeWeightMode,
         instruction_override: F,
         signers_override: Option<&[&Keypair]>,
     ) -> Result<RegistrarCookie, BanksClientError> {
         let registrar_key =
             get_registrar_address(&realm_cookie.address, &realm_cookie.account.community_mint);
 
-        let max_governance_programs = 10;
+        let max_stake_pools = 10;
 
         let data =
-            anchor_lang::InstructionData::data(&gpl_bonk_plugin::instruction::CreateRegistrar {});
+            anchor_lang::InstructionData::data(&gpl_bonk_plugin::instruction::CreateRegistrar {
+                stake_weight_mode,
+                max_stake_pools,
+            });
 
         let accounts = anchor_lang::ToAccountMetas::to_account_metas(
             &gpl_bonk_plugin::accounts::CreateRegistrar {
                 registrar: registrar_key,
                 realm: realm_cookie.address,
                 previous_voter_weight_plugin_program_id: None,
+                staking_program_id: SplTokenStakingCookie::program_id(),
                 stake_pool: *stake_pool_key,
                 governance_program_id: self.governance.program_id,
                 governing_token_mint: realm_cookie.account.community_mint,
@@ -148,17 +196,285 @@ impl BonkPluginTest {
             governance_program_id: self.governance.program_id,
             realm: realm_cookie.address,
             governing_token_mint: realm_cookie.account.community_mint,
-            reserved: [0; 8],
+            stake_weight_mode,
+            allow_voter_weight_record_close: false,
+            lockup_time_decay: false,
+            reserved: [0; 5],
+            stake_pool_configs: vec![StakePoolConfig {
+                stake_pool: *stake_pool_key,
+                weight: 1,
+                max_lockup_duration: STAKE_POOL_MAX_DURATION,
+            }],
+            staking_program_id: SplTokenStakingCookie::program_id(),
         };
 
         Ok(RegistrarCookie {
             address: registrar_key,
             account,
             realm_authority: realm_cookie.get_realm_authority(),
-            max_governance_programs,
+            max_stake_pools,
         })
     }
 
+    #[allow(dead_code)]
+    pub async fn configure_registrar(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        registrar_cookie: &mut RegistrarCookie,
+        stake_weight_mode: StakeWeightMode,
+        allow_voter_weight_record_close: bool,
+        lockup_time_decay: bool,
+        previous_voter_weight_plugin_program_id: Option<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        self.configure_registrar_using_ix(
+            realm_cookie,
+            registrar_cookie,
+            stake_weight_mode,
+            allow_voter_weight_record_close,
+            lockup_time_decay,
+            previous_voter_weight_plugin_program_id,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn configure_registrar_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        registrar_cookie: &mut RegistrarCookie,
+        stake_weight_mode: StakeWeightMode,
+        allow_voter_weight_record_close: bool,
+        lockup_time_decay: bool,
+        previous_voter_weight_plugin_program_id: Option<Pubkey>,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_bonk_plugin::instruction::ConfigureRegistrar {
+                stake_weight_mode,
+                allow_voter_weight_record_close,
+                lockup_time_decay,
+            });
+
+        let accounts = anchor_lang::ToAccountMetas::to_account_metas(
+            &gpl_bonk_plugin::accounts::ConfigureRegistrar {
+                registrar: registrar_cookie.address,
+                realm: realm_cookie.address,
+                realm_authority: realm_cookie.get_realm_authority().pubkey(),
+                previous_voter_weight_plugin_program_id,
+            },
+            None,
+        );
+
+        let mut configure_registrar_ix = Instruction {
+            program_id: gpl_bonk_plugin::id(),
+            accounts,
+            data,
+        };
+
+        instruction_override(&mut configure_registrar_ix);
+
+        let default_signers = &[&realm_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_registrar_ix], Some(signers))
+            .await?;
+
+        registrar_cookie.account.realm_authority = realm_cookie.get_realm_authority().pubkey();
+        registrar_cookie.account.stake_weight_mode = stake_weight_mode;
+        registrar_cookie.account.allow_voter_weight_record_close = allow_voter_weight_record_close;
+        registrar_cookie.account.lockup_time_decay = lockup_time_decay;
+        registrar_cookie
+            .account
+            .previous_voter_weight_plugin_program_id = previous_voter_weight_plugin_program_id;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_stake_pool(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        registrar_cookie: &mut RegistrarCookie,
+        stake_pool_key: &Pubkey,
+        weight: u64,
+    ) -> Result<(), BanksClientError> {
+        self.configure_stake_pool_using_ix(
+            realm_cookie,
+            registrar_cookie,
+            stake_pool_key,
+            weight,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn configure_stake_pool_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        registrar_cookie: &mut RegistrarCookie,
+        stake_pool_key: &Pubkey,
+        weight: u64,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_bonk_plugin::instruction::ConfigureStakePool {
+                weight,
+            });
+
+        let accounts = anchor_lang::ToAccountMetas::to_account_metas(
+            &gpl_bonk_plugin::accounts::ConfigureStakePool {
+                registrar: registrar_cookie.address,
+                realm: realm_cookie.address,
+                realm_authority: realm_cookie.get_realm_authority().pubkey(),
+                stake_pool: *stake_pool_key,
+            },
+            None,
+        );
+
+        let mut configure_stake_pool_ix = Instruction {
+            program_id: gpl_bonk_plugin::id(),
+            accounts,
+            data,
+        };
+
+        instruction_override(&mut configure_stake_pool_ix);
+
+        let default_signers = &[&realm_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[configure_stake_pool_ix], Some(signers))
+            .await?;
+
+        let stake_pool_configs = &mut registrar_cookie.account.stake_pool_configs;
+        let stake_pool_idx = stake_pool_configs
+            .iter()
+            .position(|spc| spc.stake_pool == *stake_pool_key);
+
+        match (stake_pool_idx, weight) {
+            (Some(stake_pool_idx), 0) => {
+                stake_pool_configs.remove(stake_pool_idx);
+            }
+            (Some(stake_pool_idx), weight) => stake_pool_configs[stake_pool_idx].weight = weight,
+            (None, weight) => stake_pool_configs.push(StakePoolConfig {
+                stake_pool: *stake_pool_key,
+                weight,
+                max_lockup_duration: STAKE_POOL_MAX_DURATION,
+            }),
+        }
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn resize_registrar(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        registrar_cookie: &mut RegistrarCookie,
+        max_stake_pools: u8,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_bonk_plugin::instruction::ResizeRegistrar {
+                max_stake_pools,
+            });
+
+        let accounts = anchor_lang::ToAccountMetas::to_account_metas(
+            &gpl_bonk_plugin::accounts::ResizeRegistrar {
+                registrar: registrar_cookie.address,
+                realm: realm_cookie.address,
+                governing_token_mint: realm_cookie.account.community_mint,
+                realm_authority: realm_cookie.get_realm_authority().pubkey(),
+                payer: self.bench.payer.pubkey(),
+                system_program: solana_sdk::system_program::id(),
+            },
+            None,
+        );
+
+        let resize_registrar_ix = Instruction {
+            program_id: gpl_bonk_plugin::id(),
+            accounts,
+            data,
+        };
+
+        self.bench
+            .process_transaction(
+                &[resize_registrar_ix],
+                Some(&[&realm_cookie.realm_authority]),
+            )
+            .await?;
+
+        registrar_cookie.max_stake_pools = max_stake_pools;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn migrate_registrar(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_bonk_plugin::instruction::MigrateRegistrar {});
+
+        let accounts = anchor_lang::ToAccountMetas::to_account_metas(
+            &gpl_bonk_plugin::accounts::MigrateRegistrar {
+                registrar: registrar_cookie.address,
+                payer: self.bench.payer.pubkey(),
+                system_program: solana_sdk::system_program::id(),
+            },
+            None,
+        );
+
+        let migrate_registrar_ix = Instruction {
+            program_id: gpl_bonk_plugin::id(),
+            accounts,
+            data,
+        };
+
+        self.bench
+            .process_transaction(&[migrate_registrar_ix], None)
+            .await
+    }
+
+    /// Overwrites the Registrar with the original account layout which only stored a single Stake Pool
+    #[allow(dead_code)]
+    pub async fn with_legacy_registrar_layout(&mut self, registrar_cookie: &RegistrarCookie) {
+        let mut registrar_account = self
+            .bench
+            .get_account(&registrar_cookie.address)
+            .await
+            .unwrap();
+
+        let registrar = &registrar_cookie.account;
+
+        // discriminator | governance_program_id | realm | realm_authority | governing_token_mint
+        // | stake_pool | previous_voter_weight_plugin_program_id: Option<Pubkey> | reserved: [u8; 8]
+        let mut data = registrar_account.data[..8].to_vec();
+        data.extend_from_slice(registrar.governance_program_id.as_ref());
+        data.extend_from_slice(registrar.realm.as_ref());
+        data.extend_from_slice(registrar.realm_authority.as_ref());
+        data.extend_from_slice(registrar.governing_token_mint.as_ref());
+        data.extend_from_slice(registrar.stake_pool.as_ref());
+        data.push(0);
+        data.resize(8 + 32 * 5 + 33 + 8, 0);
+
+        registrar_account.lamports = self.bench.rent.minimum_balance(data.len());
+        registrar_account.data = data;
+
+        self.bench
+            .context
+            .borrow_mut()
+            .set_account(&registrar_cookie.address, &registrar_account.into());
+    }
+
     #[allow(dead_code)]
     pub async fn with_voter_weight_record(
         &self,
@@ -307,11 +623,247 @@ impl BonkPluginTest {
         Ok(instruction)
     }
 
+    #[allow(dead_code)]
+    pub async fn with_max_voter_weight_record(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+    ) -> Result<MaxVoterWeightRecordCookie, BanksClientError> {
+        self.with_max_voter_weight_record_using_ix(registrar_cookie, NopOverride)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_max_voter_weight_record_using_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        instruction_override: F,
+    ) -> Result<MaxVoterWeightRecordCookie, BanksClientError> {
+        let max_voter_weight_record_key = get_max_voter_weight_record_address(
+            &registrar_cookie.account.realm,
+            &registrar_cookie.account.governing_token_mint,
+        );
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_bonk_plugin::instruction::CreateMaxVoterWeightRecord {},
+        );
+
+        let accounts = gpl_bonk_plugin::accounts::CreateMaxVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            max_voter_weight_record: max_voter_weight_record_key,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let mut create_max_voter_weight_record_ix = Instruction {
+            program_id: gpl_bonk_plugin::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut create_max_voter_weight_record_ix);
+
+        self.bench
+            .process_transaction(&[create_max_voter_weight_record_ix], None)
+            .await?;
+
+        let account = MaxVoterWeightRecord {
+            realm: registrar_cookie.account.realm,
+            governing_token_mint: registrar_cookie.account.governing_token_mint,
+            max_voter_weight: 0,
+            max_voter_weight_expiry: Some(0),
+            reserved: [0; 8],
+        };
+
+        Ok(MaxVoterWeightRecordCookie {
+            account,
+            address: max_voter_weight_record_key,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_max_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        input_max_voter_weight: Option<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        self.update_max_voter_weight_record_using_ix(
+            registrar_cookie,
+            max_voter_weight_record_cookie,
+            input_max_voter_weight,
+            NopOverride,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_max_voter_weight_record_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_record_cookie: &MaxVoterWeightRecordCookie,
+        input_max_voter_weight: Option<Pubkey>,
+        instruction_override: F,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_bonk_plugin::instruction::UpdateMaxVoterWeightRecord {},
+        );
+
+        let accounts = gpl_bonk_plugin::accounts::UpdateMaxVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            max_voter_weight_record: max_voter_weight_record_cookie.address,
+            input_max_voter_weight,
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        for stake_pool_config in &registrar_cookie.account.stake_pool_configs {
+            account_metas.push(AccountMeta::new_readonly(
+                stake_pool_config.stake_pool,
+                false,
+            ));
+            account_metas.push(AccountMeta::new_readonly(
+                find_vault_key(
+                    stake_pool_config.stake_pool,
+                    SplTokenStakingCookie::program_id(),
+                ),
+                false,
+            ));
+        }
+
+        let mut update_max_voter_weight_record_ix = Instruction {
+            program_id: gpl_bonk_plugin::id(),
+            accounts: account_metas,
+            data,
+        };
+
+        instruction_override(&mut update_max_voter_weight_record_ix);
+
+        self.bench
+            .process_transaction(&[update_max_voter_weight_record_ix], None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_max_voter_weight_record(
+        &self,
+        max_voter_weight_record: &Pubkey,
+    ) -> MaxVoterWeightRecord {
+        self.bench
+            .get_anchor_account(*max_voter_weight_record)
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn get_registrar_account(&mut self, registrar: &Pubkey) -> Registrar {
         self.bench.get_anchor_account::<Registrar>(*registrar).await
     }
 
+    #[allow(dead_code)]
+    pub async fn close_stake_deposit_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        voter_authority: &Keypair,
+        beneficiary: &Pubkey,
+        close_voter_weight_record: bool,
+    ) -> Result<(), BanksClientError> {
+        self.close_stake_deposit_record_using_ix(
+            registrar_cookie,
+            voter_weight_record_cookie,
+            voter_authority,
+            beneficiary,
+            close_voter_weight_record,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn close_stake_deposit_record_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+        voter_authority: &Keypair,
+        beneficiary: &Pubkey,
+        close_voter_weight_record: bool,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_bonk_plugin::instruction::CloseStakeDepositRecord {
+                close_voter_weight_record,
+            },
+        );
+
+        let accounts = gpl_bonk_plugin::accounts::CloseStakeDepositRecord {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+            stake_deposit_record: get_stake_deposit_record_address(
+                &voter_weight_record_cookie.address,
+            ),
+            voter_authority: voter_authority.pubkey(),
+            beneficiary: *beneficiary,
+        };
+
+        let mut close_stake_deposit_record_ix = Instruction {
+            program_id: gpl_bonk_plugin::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut close_stake_deposit_record_ix);
+
+        let default_signers = &[voter_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[close_stake_deposit_record_ix], Some(signers))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_stake_deposit_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+    ) -> Result<(), BanksClientError> {
+        let create_stake_deposit_record_ix =
+            self.create_stake_deposit_record_ix(registrar_cookie, voter_weight_record_cookie);
+
+        self.bench
+            .process_transaction(&[create_stake_deposit_record_ix], None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub fn create_stake_deposit_record_ix(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &VoterWeightRecordCookie,
+    ) -> Instruction {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_bonk_plugin::instruction::CreateStakeDepositRecord {},
+        );
+
+        let accounts = gpl_bonk_plugin::accounts::CreateStakeDepositRecord {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+            stake_deposit_record: get_stake_deposit_record_address(
+                &voter_weight_record_cookie.address,
+            ),
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        Instruction {
+            program_id: gpl_bonk_plugin::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        }
+    }
+
     #[allow(dead_code)]
     pub async fn get_voter_weight_record(&self, voter_weight_record: &Pubkey) -> VoterWeightRecord {
         self.bench.get_anchor_account(*voter_weight_record).await
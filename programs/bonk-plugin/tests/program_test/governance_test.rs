@@ -361,6 +361,40 @@ impl GovernanceTest {
         })
     }
 
+    #[allow(dead_code)]
+    pub async fn with_governing_token_deposit(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        token_owner_cookie: &WalletCookie,
+        amount: u64,
+    ) -> Result<(), TransportError> {
+        let token_account_cookie = self
+            .bench
+            .with_tokens(
+                &realm_cookie.community_mint_cookie,
+                &token_owner_cookie.address,
+                amount,
+            )
+            .await?;
+
+        let deposit_ix = deposit_governing_tokens(
+            &self.program_id,
+            &realm_cookie.address,
+            &token_account_cookie.address,
+            &token_owner_cookie.address,
+            &token_owner_cookie.address,
+            &self.bench.payer.pubkey(),
+            amount,
+            &realm_cookie.account.community_mint,
+        );
+
+        self.bench
+            .process_transaction(&[deposit_ix], Some(&[&token_owner_cookie.signer]))
+            .await?;
+
+        Ok(())
+    }
+
     #[allow(dead_code)]
     pub async fn cast_vote(
         &self,
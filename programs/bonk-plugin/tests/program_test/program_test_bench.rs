@@ -91,6 +91,39 @@ impl ProgramTestBench {
             .await
     }
 
+    /// Processes the transaction and returns its log messages
+    #[allow(dead_code)]
+    pub async fn process_transaction_with_logs(
+        &self,
+        instructions: &[Instruction],
+        signers: Option<&[&Keypair]>,
+    ) -> Result<Vec<String>, BanksClientError> {
+        let mut context = self.context.borrow_mut();
+
+        let mut transaction =
+            Transaction::new_with_payer(instructions, Some(&context.payer.pubkey()));
+
+        let mut all_signers = vec![&context.payer];
+
+        if let Some(signers) = signers {
+            all_signers.extend_from_slice(signers);
+        }
+
+        transaction.sign(&all_signers, context.last_blockhash);
+
+        let result = context
+            .banks_client
+            .process_transaction_with_metadata(transaction)
+            .await?;
+
+        result.result.map_err(BanksClientError::TransactionError)?;
+
+        Ok(result
+            .metadata
+            .map(|metadata| metadata.log_messages)
+            .unwrap_or_default())
+    }
+
     pub async fn get_clock(&self) -> solana_program::clock::Clock {
         self.context
             .borrow_mut()
@@ -109,6 +142,15 @@ impl ProgramTestBench {
             .unwrap();
     }
 
+    /// Moves the Clock unix_timestamp forward by the given number of seconds
+    #[allow(dead_code)]
+    pub async fn advance_clock_by_seconds(&self, seconds: i64) {
+        let mut clock = self.get_clock().await;
+        clock.unix_timestamp += seconds;
+
+        self.context.borrow_mut().set_sysvar(&clock);
+    }
+
     pub async fn with_mint(&self) -> Result<MintCookie, TransportError> {
         let mint_keypair = Keypair::new();
         let mint_authority = Keypair::new();
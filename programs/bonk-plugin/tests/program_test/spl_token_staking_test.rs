@@ -18,6 +18,9 @@ use super::program_test_bench::ProgramTestBench;
 
 declare_program!(spl_token_staking);
 
+/// Max lockup duration in seconds of the Stake Pools created by the tests
+pub const STAKE_POOL_MAX_DURATION: u64 = 1000;
+
 pub struct SplTokenStakingCookie {
     pub program_id: Pubkey,
     pub bench: Arc<ProgramTestBench>,
@@ -45,15 +48,25 @@ impl SplTokenStakingCookie {
     pub async fn with_stake_pool(
         &mut self,
         community_token_mint: &Pubkey,
+    ) -> Result<Pubkey, TransportError> {
+        self.with_stake_pool_using_nonce(community_token_mint, 0)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_stake_pool_using_nonce(
+        &mut self,
+        community_token_mint: &Pubkey,
+        nonce: u8,
     ) -> Result<Pubkey, TransportError> {
         let create_stake_pool_args = spl_token_staking::client::args::InitializeStakePool {
-            nonce: 0,
-            max_duration: 1000,
+            nonce,
+            max_duration: STAKE_POOL_MAX_DURATION,
             max_weight: u64::MAX,
             min_duration: 1,
         };
         let stake_pool_key = find_stake_pool_key(
-            0,
+            nonce,
             *community_token_mint,
             self.bench.payer.pubkey(),
             self.program_id,
@@ -1,4 +1,5 @@
-use anchor_lang::prelude::ERROR_CODE_OFFSET;
+use anchor_lang::{prelude::ERROR_CODE_OFFSET, Event};
+use base64::Engine;
 use gpl_bonk_plugin::error::BonkPluginError;
 use solana_program::instruction::InstructionError;
 use solana_program_test::BanksClientError;
@@ -10,6 +11,22 @@ pub fn clone_keypair(source: &Keypair) -> Keypair {
     Keypair::from_bytes(&source.to_bytes()).unwrap()
 }
 
+/// Returns the Anchor events of the given type emitted in the transaction logs
+#[allow(dead_code)]
+pub fn get_events<T: Event>(log_messages: &[String]) -> Vec<T> {
+    log_messages
+        .iter()
+        .filter_map(|log_message| log_message.strip_prefix("Program data: "))
+        .filter_map(|event_data| {
+            base64::engine::general_purpose::STANDARD
+                .decode(event_data)
+                .ok()
+        })
+        .filter(|event_data| event_data.starts_with(&T::DISCRIMINATOR))
+        .map(|event_data| T::deserialize(&mut &event_data[T::DISCRIMINATOR.len()..]).unwrap())
+        .collect()
+}
+
 /// NOP (No Operation) Override function
 #[allow(non_snake_case)]
 pub fn NopOverride<T>(_: &mut T) {}
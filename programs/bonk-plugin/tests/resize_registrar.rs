@@ -0,0 +1,115 @@
+mod program_test;
+
+use gpl_bonk_plugin::{error::BonkPluginError, state::Registrar};
+use program_test::{
+    bonk_plugin_test::BonkPluginTest, spl_token_staking_test::SplTokenStakingCookie,
+};
+
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+use program_test::tools::assert_bonks_plugin_err;
+
+#[tokio::test]
+async fn test_resize_registrar() -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    // Act
+    bonk_plugin_test
+        .resize_registrar(&realm_cookie, &mut registrar_cookie, 20)
+        .await?;
+
+    // Assert
+    let registrar_account = bonk_plugin_test
+        .bench
+        .get_account(&registrar_cookie.address)
+        .await
+        .unwrap();
+
+    assert_eq!(registrar_account.data.len(), Registrar::get_space(20));
+
+    let registrar = bonk_plugin_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar, registrar_cookie.account);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resize_registrar_to_configured_stake_pools() -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    // Act
+    bonk_plugin_test
+        .resize_registrar(&realm_cookie, &mut registrar_cookie, 1)
+        .await?;
+
+    // Assert
+    let registrar_account = bonk_plugin_test
+        .bench
+        .get_account(&registrar_cookie.address)
+        .await
+        .unwrap();
+
+    assert_eq!(registrar_account.data.len(), Registrar::get_space(1));
+
+    let registrar = bonk_plugin_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar, registrar_cookie.account);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resize_registrar_below_configured_stake_pools_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    // Act
+    let err = bonk_plugin_test
+        .resize_registrar(&realm_cookie, &mut registrar_cookie, 0)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_bonks_plugin_err(err, BonkPluginError::InvalidResizeMaxStakePools);
+
+    Ok(())
+}
@@ -0,0 +1,376 @@
+use crate::program_test::bonk_plugin_test::BonkPluginTest;
+use gpl_bonk_plugin::{error::BonkPluginError, state::StakeWeightMode};
+use program_test::program_test_bench::airdrop;
+use program_test::spl_token_staking_test::find_stake_receipt_key;
+use program_test::{spl_token_staking_test::SplTokenStakingCookie, tools::*};
+use solana_program_test::*;
+use solana_sdk::native_token::sol_to_lamports;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::{signature::Keypair, transport::TransportError};
+
+mod program_test;
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record() -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let max_voter_weight_record_cookie = bonk_plugin_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let depositor = Keypair::new();
+    airdrop(
+        &mut bonk_plugin_test.bench.context.borrow_mut(),
+        &depositor.pubkey(),
+        sol_to_lamports(10.0),
+    )
+    .await?;
+    let token_account_cookie = bonk_plugin_test
+        .bench
+        .with_tokens(
+            &realm_cookie.community_mint_cookie,
+            &depositor.pubkey(),
+            100,
+        )
+        .await?;
+
+    let stake_pool_reciept = find_stake_receipt_key(
+        depositor.pubkey(),
+        stake_pool_pubkey,
+        0,
+        spl_token_staking_cookie.program_id,
+    );
+    spl_token_staking_cookie
+        .deposit_into_stake_pool(
+            &depositor,
+            &stake_pool_pubkey,
+            &stake_pool_reciept,
+            &token_account_cookie.address,
+            &[],
+        )
+        .await?;
+
+    // Act
+    bonk_plugin_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(realm_cookie.account.community_mint),
+        )
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = bonk_plugin_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    let clock = bonk_plugin_test.bench.get_clock().await;
+
+    // 100 supply of the governing token mint and 100 staked in the pool with weight 1
+    assert_eq!(max_voter_weight_record.max_voter_weight, 200);
+    assert_eq!(
+        max_voter_weight_record.max_voter_weight_expiry,
+        Some(clock.slot)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_multiple_stake_pools(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let stake_pool_pubkey2 = spl_token_staking_cookie
+        .with_stake_pool_using_nonce(&realm_cookie.community_mint_cookie.address, 1)
+        .await?;
+
+    bonk_plugin_test
+        .configure_stake_pool(&realm_cookie, &mut registrar_cookie, &stake_pool_pubkey2, 2)
+        .await?;
+
+    let max_voter_weight_record_cookie = bonk_plugin_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let depositor = Keypair::new();
+    airdrop(
+        &mut bonk_plugin_test.bench.context.borrow_mut(),
+        &depositor.pubkey(),
+        sol_to_lamports(10.0),
+    )
+    .await?;
+
+    for stake_pool_pubkey in [stake_pool_pubkey, stake_pool_pubkey2] {
+        let token_account_cookie = bonk_plugin_test
+            .bench
+            .with_tokens(
+                &realm_cookie.community_mint_cookie,
+                &depositor.pubkey(),
+                100,
+            )
+            .await?;
+
+        let stake_pool_reciept = find_stake_receipt_key(
+            depositor.pubkey(),
+            stake_pool_pubkey,
+            0,
+            spl_token_staking_cookie.program_id,
+        );
+        spl_token_staking_cookie
+            .deposit_into_stake_pool(
+                &depositor,
+                &stake_pool_pubkey,
+                &stake_pool_reciept,
+                &token_account_cookie.address,
+                &[],
+            )
+            .await?;
+    }
+
+    // Act
+    bonk_plugin_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(realm_cookie.account.community_mint),
+        )
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = bonk_plugin_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    // 200 supply of the governing token mint,
+    // 100 staked in the pool with weight 1 and 100 staked in the pool with weight 2
+    assert_eq!(max_voter_weight_record.max_voter_weight, 500);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_missing_stake_pool_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let max_voter_weight_record_cookie = bonk_plugin_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = bonk_plugin_test
+        .update_max_voter_weight_record_using_ix(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+            |i| i.accounts.truncate(3), // stake_pool and stake_pool_vault
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_bonks_plugin_err(err, BonkPluginError::StakePoolAccountsCountMismatch);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_invalid_stake_pool_vault_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let max_voter_weight_record_cookie = bonk_plugin_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let token_account_cookie = bonk_plugin_test
+        .bench
+        .with_token_account(&realm_cookie.account.community_mint)
+        .await?;
+
+    // Act
+    let err = bonk_plugin_test
+        .update_max_voter_weight_record_using_ix(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            None,
+            |i| i.accounts[4].pubkey = token_account_cookie.address, // stake_pool_vault
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_bonks_plugin_err(err, BonkPluginError::InvalidStakePoolVault);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_missing_input_max_voter_weight_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    bonk_plugin_test
+        .configure_registrar(
+            &realm_cookie,
+            &mut registrar_cookie,
+            StakeWeightMode::DepositAmount,
+            false,
+            false,
+            Some(Pubkey::new_unique()),
+        )
+        .await?;
+
+    let max_voter_weight_record_cookie = bonk_plugin_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = bonk_plugin_test
+        .update_max_voter_weight_record(&registrar_cookie, &max_voter_weight_record_cookie, None)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_bonks_plugin_err(err, BonkPluginError::MissingInputMaxVoterWeight);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_token_owner_record_deposit(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let max_voter_weight_record_cookie = bonk_plugin_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    let voter_cookie = bonk_plugin_test.bench.with_wallet().await;
+
+    bonk_plugin_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    bonk_plugin_test
+        .governance
+        .with_governing_token_deposit(&realm_cookie, &voter_cookie, 50)
+        .await?;
+
+    // Act
+    bonk_plugin_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            Some(realm_cookie.account.community_mint),
+        )
+        .await?;
+
+    // Assert
+    let max_voter_weight_record = bonk_plugin_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    // The TokenOwnerRecord deposit counts toward the voter weight and it's bounded by the mint supply
+    assert_eq!(max_voter_weight_record.max_voter_weight, 50);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_without_predecessor_and_missing_mint_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let max_voter_weight_record_cookie = bonk_plugin_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = bonk_plugin_test
+        .update_max_voter_weight_record(&registrar_cookie, &max_voter_weight_record_cookie, None)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_bonks_plugin_err(err, BonkPluginError::MissingInputMaxVoterWeight);
+
+    Ok(())
+}
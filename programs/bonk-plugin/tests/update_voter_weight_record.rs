@@ -1,6 +1,15 @@
 use crate::program_test::bonk_plugin_test::BonkPluginTest;
+use anchor_lang::AnchorDeserialize;
+use gpl_bonk_plugin::error::BonkPluginError;
+use gpl_bonk_plugin::events::{StakeDepositWeight, VoterWeightRecordUpdated};
+use gpl_bonk_plugin::state::{StakeWeightMode, VoterWeightAction};
+use gpl_bonk_plugin::utils::{
+    stake_deposit_receipt::StakeDepositReceipt, stake_pool::SCALE_FACTOR_BASE,
+};
 use program_test::program_test_bench::{airdrop, WalletCookie};
-use program_test::spl_token_staking_test::{find_reward_vault_key, find_stake_receipt_key};
+use program_test::spl_token_staking_test::{
+    find_reward_vault_key, find_stake_receipt_key, STAKE_POOL_MAX_DURATION,
+};
 use program_test::{spl_token_staking_test::SplTokenStakingCookie, tools::*};
 use solana_program_test::*;
 use solana_sdk::native_token::sol_to_lamports;
@@ -468,3 +477,534 @@ async fn test_update_voter_weight_record_vote_invalid_action() -> Result<(), Tra
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_effective_stake() -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let registrar_cookie = bonk_plugin_test
+        .with_registrar_with_stake_weight_mode(
+            &realm_cookie,
+            &stake_pool_pubkey,
+            StakeWeightMode::EffectiveStake,
+        )
+        .await?;
+
+    let depositor = Keypair::new();
+    airdrop(
+        &mut bonk_plugin_test.bench.context.borrow_mut(),
+        &depositor.pubkey(),
+        sol_to_lamports(10.0),
+    )
+    .await?;
+    let token_account_cookie = bonk_plugin_test
+        .bench
+        .with_tokens(
+            &realm_cookie.community_mint_cookie,
+            &depositor.pubkey(),
+            100,
+        )
+        .await?;
+
+    let stake_deposit_receipt = find_stake_receipt_key(
+        depositor.pubkey(),
+        stake_pool_pubkey,
+        0,
+        spl_token_staking_cookie.program_id,
+    );
+    spl_token_staking_cookie
+        .deposit_into_stake_pool(
+            &depositor,
+            &stake_pool_pubkey,
+            &stake_deposit_receipt,
+            &token_account_cookie.address,
+            &[],
+        )
+        .await?;
+
+    let voter_cookie = WalletCookie {
+        address: depositor.pubkey(),
+        signer: clone_keypair(&depositor),
+    };
+    let voter_weight_record_cookie = bonk_plugin_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+    let token_owner_record_cookie = bonk_plugin_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    // The governance account is only used for CastVote and the Realm is owned by spl-governance
+    let update_voter_weight_record_ix = bonk_plugin_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &token_owner_record_cookie.address,
+            &token_owner_record_cookie,
+            realm_cookie.address,
+            VoterWeightAction::CreateGovernance,
+            None,
+            &depositor,
+            realm_cookie.address,
+            &Some(vec![stake_deposit_receipt]),
+        )
+        .await?;
+
+    // Act
+    bonk_plugin_test
+        .bench
+        .process_transaction(&[update_voter_weight_record_ix], Some(&[&depositor]))
+        .await?;
+
+    // Assert
+    let stake_deposit_receipt_data = bonk_plugin_test
+        .bench
+        .get_account(&stake_deposit_receipt)
+        .await
+        .unwrap()
+        .data;
+    let stake_deposit_receipt =
+        StakeDepositReceipt::try_from_slice(&stake_deposit_receipt_data).unwrap();
+
+    let voter_weight_record = bonk_plugin_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    // The effective stake is stored in terms of 1 / SCALE_FACTOR_BASE and it's weighted by the lockup duration
+    assert_eq!(stake_deposit_receipt.deposit_amount, 100);
+    assert_eq!(
+        voter_weight_record.voter_weight as u128,
+        stake_deposit_receipt.effective_stake / SCALE_FACTOR_BASE as u128
+    );
+    assert!(voter_weight_record.voter_weight > stake_deposit_receipt.deposit_amount);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_multiple_stake_pools() -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let stake_pool_pubkey2 = spl_token_staking_cookie
+        .with_stake_pool_using_nonce(&realm_cookie.community_mint_cookie.address, 1)
+        .await?;
+
+    bonk_plugin_test
+        .configure_stake_pool(&realm_cookie, &mut registrar_cookie, &stake_pool_pubkey2, 3)
+        .await?;
+
+    let depositor = Keypair::new();
+    airdrop(
+        &mut bonk_plugin_test.bench.context.borrow_mut(),
+        &depositor.pubkey(),
+        sol_to_lamports(10.0),
+    )
+    .await?;
+
+    let mut stake_deposit_receipts = vec![];
+
+    for stake_pool_pubkey in [stake_pool_pubkey, stake_pool_pubkey2] {
+        let token_account_cookie = bonk_plugin_test
+            .bench
+            .with_tokens(
+                &realm_cookie.community_mint_cookie,
+                &depositor.pubkey(),
+                100,
+            )
+            .await?;
+
+        let stake_deposit_receipt = find_stake_receipt_key(
+            depositor.pubkey(),
+            stake_pool_pubkey,
+            0,
+            spl_token_staking_cookie.program_id,
+        );
+        spl_token_staking_cookie
+            .deposit_into_stake_pool(
+                &depositor,
+                &stake_pool_pubkey,
+                &stake_deposit_receipt,
+                &token_account_cookie.address,
+                &[],
+            )
+            .await?;
+
+        stake_deposit_receipts.push(stake_deposit_receipt);
+    }
+
+    let voter_cookie = WalletCookie {
+        address: depositor.pubkey(),
+        signer: clone_keypair(&depositor),
+    };
+    let voter_weight_record_cookie = bonk_plugin_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+    let token_owner_record_cookie = bonk_plugin_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    // The governance account is only used for CastVote and the Realm is owned by spl-governance
+    let update_voter_weight_record_ix = bonk_plugin_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &token_owner_record_cookie.address,
+            &token_owner_record_cookie,
+            realm_cookie.address,
+            VoterWeightAction::CreateGovernance,
+            None,
+            &depositor,
+            realm_cookie.address,
+            &Some(stake_deposit_receipts),
+        )
+        .await?;
+
+    // Act
+    bonk_plugin_test
+        .bench
+        .process_transaction(&[update_voter_weight_record_ix], Some(&[&depositor]))
+        .await?;
+
+    // Assert
+    let voter_weight_record = bonk_plugin_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    // 100 staked in the pool with weight 1 and 100 staked in the pool with weight 3
+    assert_eq!(voter_weight_record.voter_weight, 400);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_not_configured_stake_pool_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    // The Stake Pool belongs to the same spl-token-staking instance but it's not configured in the Registrar
+    let stake_pool_pubkey2 = spl_token_staking_cookie
+        .with_stake_pool_using_nonce(&realm_cookie.community_mint_cookie.address, 1)
+        .await?;
+
+    let depositor = Keypair::new();
+    airdrop(
+        &mut bonk_plugin_test.bench.context.borrow_mut(),
+        &depositor.pubkey(),
+        sol_to_lamports(10.0),
+    )
+    .await?;
+    let token_account_cookie = bonk_plugin_test
+        .bench
+        .with_tokens(
+            &realm_cookie.community_mint_cookie,
+            &depositor.pubkey(),
+            100,
+        )
+        .await?;
+
+    let stake_deposit_receipt = find_stake_receipt_key(
+        depositor.pubkey(),
+        stake_pool_pubkey2,
+        0,
+        spl_token_staking_cookie.program_id,
+    );
+    spl_token_staking_cookie
+        .deposit_into_stake_pool(
+            &depositor,
+            &stake_pool_pubkey2,
+            &stake_deposit_receipt,
+            &token_account_cookie.address,
+            &[],
+        )
+        .await?;
+
+    let voter_cookie = WalletCookie {
+        address: depositor.pubkey(),
+        signer: clone_keypair(&depositor),
+    };
+    let voter_weight_record_cookie = bonk_plugin_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+    let token_owner_record_cookie = bonk_plugin_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    // The governance account is only used for CastVote and the Realm is owned by spl-governance
+    let update_voter_weight_record_ix = bonk_plugin_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &token_owner_record_cookie.address,
+            &token_owner_record_cookie,
+            realm_cookie.address,
+            VoterWeightAction::CreateGovernance,
+            None,
+            &depositor,
+            realm_cookie.address,
+            &Some(vec![stake_deposit_receipt]),
+        )
+        .await?;
+
+    // Act
+    let err = bonk_plugin_test
+        .bench
+        .process_transaction(&[update_voter_weight_record_ix], Some(&[&depositor]))
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_bonks_plugin_err(err, BonkPluginError::InvalidStakePool);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_lockup_time_decay() -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let mut registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    bonk_plugin_test
+        .configure_registrar(
+            &realm_cookie,
+            &mut registrar_cookie,
+            StakeWeightMode::DepositAmount,
+            false,
+            true,
+            None,
+        )
+        .await?;
+
+    let depositor = Keypair::new();
+    airdrop(
+        &mut bonk_plugin_test.bench.context.borrow_mut(),
+        &depositor.pubkey(),
+        sol_to_lamports(10.0),
+    )
+    .await?;
+    let token_account_cookie = bonk_plugin_test
+        .bench
+        .with_tokens(
+            &realm_cookie.community_mint_cookie,
+            &depositor.pubkey(),
+            100,
+        )
+        .await?;
+
+    // The deposit is locked up for STAKE_POOL_MAX_DURATION
+    let stake_deposit_receipt = find_stake_receipt_key(
+        depositor.pubkey(),
+        stake_pool_pubkey,
+        0,
+        spl_token_staking_cookie.program_id,
+    );
+    spl_token_staking_cookie
+        .deposit_into_stake_pool(
+            &depositor,
+            &stake_pool_pubkey,
+            &stake_deposit_receipt,
+            &token_account_cookie.address,
+            &[],
+        )
+        .await?;
+
+    let voter_cookie = WalletCookie {
+        address: depositor.pubkey(),
+        signer: clone_keypair(&depositor),
+    };
+    let voter_weight_record_cookie = bonk_plugin_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+    let token_owner_record_cookie = bonk_plugin_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    // Half of the lockup duration elapses
+    bonk_plugin_test
+        .bench
+        .advance_clock_by_seconds(STAKE_POOL_MAX_DURATION as i64 / 2)
+        .await;
+
+    // The governance account is only used for CastVote and the Realm is owned by spl-governance
+    let update_voter_weight_record_ix = bonk_plugin_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &token_owner_record_cookie.address,
+            &token_owner_record_cookie,
+            realm_cookie.address,
+            VoterWeightAction::CreateGovernance,
+            None,
+            &depositor,
+            realm_cookie.address,
+            &Some(vec![stake_deposit_receipt]),
+        )
+        .await?;
+
+    // Act
+    bonk_plugin_test
+        .bench
+        .process_transaction(&[update_voter_weight_record_ix], Some(&[&depositor]))
+        .await?;
+
+    // Assert
+    let voter_weight_record = bonk_plugin_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    // The weight decays linearly with the remaining half of the max lockup duration
+    assert_eq!(voter_weight_record.voter_weight, 50);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_emits_voter_weight_record_updated_event(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut bonk_plugin_test = BonkPluginTest::start_new().await;
+
+    let realm_cookie = bonk_plugin_test.governance.with_realm().await?;
+    let mut spl_token_staking_cookie = SplTokenStakingCookie::new(bonk_plugin_test.bench.clone());
+    let stake_pool_pubkey = spl_token_staking_cookie
+        .with_stake_pool(&realm_cookie.community_mint_cookie.address)
+        .await?;
+    let registrar_cookie = bonk_plugin_test
+        .with_registrar(&realm_cookie, &stake_pool_pubkey)
+        .await?;
+
+    let depositor = Keypair::new();
+    airdrop(
+        &mut bonk_plugin_test.bench.context.borrow_mut(),
+        &depositor.pubkey(),
+        sol_to_lamports(10.0),
+    )
+    .await?;
+    let token_account_cookie = bonk_plugin_test
+        .bench
+        .with_tokens(
+            &realm_cookie.community_mint_cookie,
+            &depositor.pubkey(),
+            100,
+        )
+        .await?;
+
+    let stake_deposit_receipt = find_stake_receipt_key(
+        depositor.pubkey(),
+        stake_pool_pubkey,
+        0,
+        spl_token_staking_cookie.program_id,
+    );
+    spl_token_staking_cookie
+        .deposit_into_stake_pool(
+            &depositor,
+            &stake_pool_pubkey,
+            &stake_deposit_receipt,
+            &token_account_cookie.address,
+            &[],
+        )
+        .await?;
+
+    let voter_cookie = WalletCookie {
+        address: depositor.pubkey(),
+        signer: clone_keypair(&depositor),
+    };
+    let voter_weight_record_cookie = bonk_plugin_test
+        .with_voter_weight_record(&registrar_cookie, &voter_cookie)
+        .await?;
+    let token_owner_record_cookie = bonk_plugin_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &voter_cookie)
+        .await?;
+
+    bonk_plugin_test
+        .governance
+        .with_governing_token_deposit(&realm_cookie, &voter_cookie, 25)
+        .await?;
+
+    // The governance account is only used for CastVote and the Realm is owned by spl-governance
+    let update_voter_weight_record_ix = bonk_plugin_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &voter_weight_record_cookie,
+            &token_owner_record_cookie.address,
+            &token_owner_record_cookie,
+            realm_cookie.address,
+            VoterWeightAction::CreateGovernance,
+            None,
+            &depositor,
+            realm_cookie.address,
+            &Some(vec![stake_deposit_receipt]),
+        )
+        .await?;
+
+    // Act
+    let log_messages = bonk_plugin_test
+        .bench
+        .process_transaction_with_logs(&[update_voter_weight_record_ix], Some(&[&depositor]))
+        .await?;
+
+    // Assert
+    let events = get_events::<VoterWeightRecordUpdated>(&log_messages);
+
+    assert_eq!(events.len(), 1);
+
+    let event = &events[0];
+
+    assert_eq!(
+        event.voter_weight_record,
+        voter_weight_record_cookie.address
+    );
+    assert_eq!(event.governing_token_owner, depositor.pubkey());
+    assert_eq!(event.action, VoterWeightAction::CreateGovernance);
+    assert_eq!(event.action_target, realm_cookie.address);
+    assert_eq!(
+        event.stake_deposit_weights,
+        vec![StakeDepositWeight {
+            stake_deposit_receipt,
+            weight: 100,
+        }]
+    );
+    assert_eq!(event.predecessor_voter_weight, 25);
+    assert_eq!(event.voter_weight, 125);
+
+    Ok(())
+}
@@ -22,4 +22,37 @@ pub enum RealmVoterError {
 
     #[msg("Governing TokenOwner must match")]
     GoverningTokenOwnerMustMatch,
+
+    #[msg("TokenOwnerRecord governing token deposit is below the configured minimum")]
+    InsufficientGoverningTokenDeposit,
+
+    #[msg("Partner Realm and RealmConfig must be provided together")]
+    PartnerRealmConfigRequired,
+
+    #[msg("Invalid VoterWeightRecord Realm for partner Realm")]
+    InvalidPartnerVoterWeightRecordRealm,
+
+    #[msg("VoterWeightRecord is not owned by a voter weight plugin configured for partner Realm")]
+    InvalidPartnerVoterWeightRecordPlugin,
+
+    #[msg("Partner VoterWeightRecord has expired")]
+    PartnerVoterWeightRecordExpired,
+
+    #[msg("Partner VoterWeightRecord has no voter weight")]
+    PartnerVoterWeightRecordWithoutWeight,
+
+    #[msg("Previous voter weight plugin required but not provided")]
+    MissingPreviousVoterWeightPlugin,
+
+    #[msg("Input voter weight required when previous voter weight plugin is configured")]
+    MissingInputVoterWeight,
+
+    #[msg("Resizing max governance programs below the number of configured governance programs is not allowed")]
+    InvalidResizeMaxGovernancePrograms,
+
+    #[msg("Voter weight arithmetic overflow")]
+    VoterWeightOverflow,
+
+    #[msg("Realm membership required when input voter weight is not provided")]
+    MissingRealmMembership,
 }
@@ -11,8 +11,9 @@ use crate::error::RealmVoterError;
 use crate::state::{CollectionItemChangeType, GovernanceProgramConfig, Registrar};
 
 /// Creates or updates configuration for spl-governance program instances to define which spl-governance instances can be used to grant governance power
+/// min_governing_token_deposit_amount sets the deposit a TokenOwnerRecord must hold to be recognised as membership
 #[derive(Accounts)]
-#[instruction(change_type: CollectionItemChangeType)]
+#[instruction(change_type: CollectionItemChangeType, min_governing_token_deposit_amount: u64)]
 pub struct ConfigureGovernanceProgram<'info> {
     /// Registrar which we configure the provided spl-governance instance for
     #[account(mut)]
@@ -39,6 +40,7 @@ pub struct ConfigureGovernanceProgram<'info> {
 pub fn configure_governance_program(
     ctx: Context<ConfigureGovernanceProgram>,
     change_type: CollectionItemChangeType,
+    min_governing_token_deposit_amount: u64,
 ) -> Result<()> {
     let registrar = &mut ctx.accounts.registrar;
 
@@ -58,7 +60,7 @@ pub fn configure_governance_program(
 
     let governance_program_config = GovernanceProgramConfig {
         program_id: governance_program_id.key(),
-        reserved: [0; 8],
+        min_governing_token_deposit_amount,
     };
 
     let governance_program_config_idx = registrar
@@ -69,13 +71,12 @@ pub fn configure_governance_program(
     match (change_type, governance_program_config_idx) {
         // Update
         (CollectionItemChangeType::Upsert, Some(config_idx)) => {
-            // Note: Update in this version is nop because we only store governance_program_id
             registrar.governance_program_configs[config_idx] = governance_program_config;
         }
         // Insert
         (CollectionItemChangeType::Upsert, None) => {
-            // Note: In the current version push() would throw an error if we exceed
-            // max_governance_programs specified when the Registrar was created
+            // Note: push() would throw an error if we exceed max_governance_programs
+            // the Registrar was created or resized with, use resize_registrar to allocate more space
             registrar
                 .governance_program_configs
                 .push(governance_program_config);
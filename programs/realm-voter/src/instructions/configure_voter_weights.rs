@@ -4,7 +4,7 @@ use crate::state::*;
 use anchor_lang::prelude::*;
 use spl_governance::state::realm;
 
-/// Configures realm_member_voter_weight and max_voter_weight for Registrar
+/// Configures realm_member_voter_weight, max_voter_weight and aggregate_realm_memberships for Registrar
 /// It also sets MaxVoterWeightRecord.max_voter_weight to the provided value
 /// MaxVoterWeightRecord.max_voter_weight is static and can only be set using this instruction and hence it never expires
 #[derive(Accounts)]
@@ -39,12 +39,14 @@ pub fn configure_voter_weights(
     ctx: Context<ConfigureVoterWeights>,
     realm_member_voter_weight: u64,
     max_voter_weight: u64,
+    aggregate_realm_memberships: bool,
 ) -> Result<()> {
     let registrar = &mut ctx.accounts.registrar;
     // Note: max_voter_weight is stored on Registrar for consistency sake to have the registrar as the source of truth for configuration
     // And MaxVoterWeightRecord.max_voter_weight is used as data exchange account
     registrar.realm_member_voter_weight = realm_member_voter_weight;
     registrar.max_voter_weight = max_voter_weight;
+    registrar.aggregate_realm_memberships = aggregate_realm_memberships;
 
     let realm = realm::get_realm_data_for_governing_token_mint(
         &registrar.governance_program_id,
@@ -7,7 +7,7 @@ use spl_governance::state::realm;
 /// Creates Registrar storing Realm Voter configuration for spl-governance Realm
 /// This instruction should only be executed once per realm/governing_token_mint to create the account
 #[derive(Accounts)]
-#[instruction(max_governance_programs: u8)]
+#[instruction(max_governance_programs: u8, use_previous_voter_weight_plugin: bool)]
 pub struct CreateRegistrar<'info> {
     /// The Realm Voter Registrar
     /// There can only be a single registrar per governance Realm and governing mint of the Realm
@@ -59,12 +59,32 @@ pub struct CreateRegistrar<'info> {
 ///
 /// max_governance_programs is used to allocate account size for the maximum number of configured spl-governance instances
 /// Note: Once Solana runtime supports account resizing the max value won't be required
-pub fn create_registrar(ctx: Context<CreateRegistrar>, _max_governance_programs: u8) -> Result<()> {
+///
+/// When use_previous_voter_weight_plugin is set the previous plugin program id must be provided as the first remaining account
+pub fn create_registrar(
+    ctx: Context<CreateRegistrar>,
+    _max_governance_programs: u8,
+    use_previous_voter_weight_plugin: bool,
+) -> Result<()> {
     let registrar = &mut ctx.accounts.registrar;
     registrar.governance_program_id = ctx.accounts.governance_program_id.key();
     registrar.realm = ctx.accounts.realm.key();
     registrar.governing_token_mint = ctx.accounts.governing_token_mint.key();
 
+    let remaining_accounts = &ctx.remaining_accounts;
+
+    // If the plugin has a previous voter weight plugin, it "inherits" the vote weight from a vote_weight_account owned
+    // by the previous plugin. This chain is registered here.
+    registrar.previous_voter_weight_plugin_program_id = use_previous_voter_weight_plugin
+        .then(|| {
+            remaining_accounts
+                .first()
+                .ok_or(RealmVoterError::MissingPreviousVoterWeightPlugin)
+                .map(|account| account.key)
+        })
+        .transpose()?
+        .copied();
+
     // Verify that realm_authority is the expected authority of the Realm
     // and that the mint matches one of the realm mints too
     let realm = realm::get_realm_data_for_governing_token_mint(
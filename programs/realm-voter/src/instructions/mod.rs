@@ -4,6 +4,9 @@ mod configure_governance_program;
 pub use create_registrar::*;
 mod create_registrar;
 
+pub use resize_registrar::*;
+mod resize_registrar;
+
 pub use create_voter_weight_record::*;
 mod create_voter_weight_record;
 
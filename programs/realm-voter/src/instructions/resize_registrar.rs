@@ -0,0 +1,84 @@
+use crate::error::RealmVoterError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use spl_governance::state::realm;
+
+/// Resizes Registrar storing Realm Voter configuration for spl-governance Realm
+/// This instruction can only be executed if max_governance_programs is not lower than the number of currently configured governance_program_configs
+#[derive(Accounts)]
+#[instruction(max_governance_programs: u8)]
+pub struct ResizeRegistrar<'info> {
+    /// The Realm Voter Registrar
+    /// There can only be a single registrar per governance Realm and governing mint of the Realm
+    #[account(
+        mut,
+        seeds = [b"registrar".as_ref(), realm.key().as_ref(), governing_token_mint.key().as_ref()],
+        bump,
+        realloc = Registrar::get_space(max_governance_programs),
+        realloc::payer = payer,
+        realloc::zero = false,
+    )]
+    pub registrar: Account<'info, Registrar>,
+
+    /// The program id of the spl-governance program the realm belongs to
+    /// CHECK: Can be any instance of spl-governance and it's not known at the compilation time
+    #[account(executable)]
+    pub governance_program_id: UncheckedAccount<'info>,
+
+    /// An spl-governance Realm
+    ///
+    /// Realm is validated in the instruction:
+    /// - Realm is owned by the governance_program_id
+    /// - governing_token_mint must be the community or council mint
+    /// - realm_authority is realm.authority
+    ///
+    /// CHECK: Owned by spl-governance instance specified in governance_program_id
+    #[account(owner = governance_program_id.key())]
+    pub realm: UncheckedAccount<'info>,
+
+    /// Either the realm community mint or the council mint.
+    /// It must match Realm.community_mint or Realm.config.council_mint
+    ///
+    /// Note: Once the Realm voter plugin is enabled the governing_token_mint is used only as identity
+    /// for the voting population and the tokens of that are no longer used
+    pub governing_token_mint: Account<'info, Mint>,
+
+    /// realm_authority must sign and match Realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// Payer funds the increased account size and receives the rent refund when the account shrinks
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Resizes a Registrar which stores Realms voter configuration for the given Realm
+///
+/// max_governance_programs is used to allocate account size for the maximum number of configured spl-governance instances
+pub fn resize_registrar(ctx: Context<ResizeRegistrar>, max_governance_programs: u8) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    require_gte!(
+        max_governance_programs as usize,
+        registrar.governance_program_configs.len(),
+        RealmVoterError::InvalidResizeMaxGovernancePrograms
+    );
+
+    // Verify that realm_authority is the expected authority of the Realm
+    // and that the mint matches one of the realm mints too
+    let realm = realm::get_realm_data_for_governing_token_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.realm,
+        &registrar.governing_token_mint,
+    )?;
+
+    require_eq!(
+        realm.authority.unwrap(),
+        ctx.accounts.realm_authority.key(),
+        RealmVoterError::InvalidRealmAuthority
+    );
+
+    Ok(())
+}
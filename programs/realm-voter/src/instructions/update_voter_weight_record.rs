@@ -1,10 +1,27 @@
 use crate::error::RealmVoterError;
 use crate::state::*;
 use anchor_lang::prelude::*;
-use spl_governance::state::token_owner_record;
+use gpl_shared::compose::resolve_input_voter_weight;
+use gpl_shared::generic_voter_weight::GenericVoterWeight;
+use num_traits::FromPrimitive;
+use spl_governance::addins::voter_weight::get_voter_weight_record_data;
+use spl_governance::state::{realm, realm_config, token_owner_record};
+use std::cmp::min;
 
 /// Updates VoterWeightRecord based on Realm DAO membership
 /// The membership is evaluated via a valid TokenOwnerRecord which must belong to one of the configured spl-governance instances
+/// and hold at least the min_governing_token_deposit_amount configured for the instance
+///
+/// Alternatively, when partner_realm and partner_realm_config are provided, the membership is evaluated via a VoterWeightRecord
+/// owned by the voter weight plugin configured for the partner Realm
+/// It allows to recognise members of partner Realms which use plugins and hence have no governing tokens deposited
+///
+/// When registrar.aggregate_realm_memberships is enabled then TokenOwnerRecords of additional Realms can be provided
+/// in remaining_accounts and each distinct Realm the voter is a member of adds realm_member_voter_weight to the voter weight
+///
+/// When input_voter_weight is provided the realm membership weight is added on top of the input voter weight
+/// which is either the voter's TokenOwnerRecord of the Realm or VoterWeightRecord of the previous plugin
+/// The membership is then optional and the input voter weight is passed through when it's not provided
 ///
 /// This instruction sets VoterWeightRecord.voter_weight which is valid for the current slot only
 /// and must be executed inside the same transaction as the corresponding spl-gov instruction
@@ -24,54 +41,254 @@ pub struct UpdateVoterWeightRecord<'info> {
     pub voter_weight_record: Account<'info, VoterWeightRecord>,
 
     /// TokenOwnerRecord for any of the configured spl-governance instances
+    /// or VoterWeightRecord of the partner Realm plugin when partner_realm and partner_realm_config are provided
+    /// It can be omitted when input_voter_weight is provided and then no realm membership weight is granted
     /// CHECK: Owned by any of the spl-governance instances specified in registrar.governance_program_configs
-    pub token_owner_record: UncheckedAccount<'info>,
+    /// or by the voter weight plugin configured for partner_realm
+    pub token_owner_record: Option<UncheckedAccount<'info>>,
+
+    /// The partner Realm the VoterWeightRecord provided as token_owner_record belongs to
+    /// CHECK: Owned by any of the spl-governance instances specified in registrar.governance_program_configs
+    pub partner_realm: Option<UncheckedAccount<'info>>,
+
+    /// RealmConfig of the partner Realm which defines the voter weight plugins of the Realm
+    /// CHECK: Owned by partner_realm.owner and validated for partner_realm
+    pub partner_realm_config: Option<UncheckedAccount<'info>>,
+
+    /// An account that is either of type TokenOwnerRecordV2 or VoterWeightRecord
+    /// depending on whether the registrar includes a predecessor or not
+    /// It can be omitted when the registrar has no predecessor and then only realm membership weight is granted
+    /// CHECK: Checked in the code depending on the registrar
+    pub input_voter_weight: Option<UncheckedAccount<'info>>,
+}
+
+/// Realm membership established by TokenOwnerRecord or partner Realm VoterWeightRecord
+struct RealmMembership {
+    realm: Pubkey,
+    governing_token_owner: Pubkey,
+}
+
+impl RealmMembership {
+    fn assert_is_valid(
+        &self,
+        registrar: &Registrar,
+        voter_weight_record: &VoterWeightRecord,
+    ) -> Result<()> {
+        // Ensure VoterWeightRecord and the membership are for the same governing_token_owner
+        require_eq!(
+            self.governing_token_owner,
+            voter_weight_record.governing_token_owner,
+            RealmVoterError::GoverningTokenOwnerMustMatch
+        );
+
+        // Membership of the Realm the plugin is configured for is not allowed as a source of governance power
+        require_neq!(
+            self.realm,
+            registrar.realm,
+            RealmVoterError::TokenOwnerRecordFromOwnRealmNotAllowed
+        );
+
+        Ok(())
+    }
 }
 
 pub fn update_voter_weight_record(ctx: Context<UpdateVoterWeightRecord>) -> Result<()> {
     let registrar = &ctx.accounts.registrar;
     let voter_weight_record = &mut ctx.accounts.voter_weight_record;
 
-    let governance_program_id = ctx.accounts.token_owner_record.owner;
+    let membership = match (
+        &ctx.accounts.token_owner_record,
+        &ctx.accounts.partner_realm,
+        &ctx.accounts.partner_realm_config,
+    ) {
+        (None, None, None) => None,
+        (Some(token_owner_record), None, None) => Some(resolve_token_owner_record_membership(
+            registrar,
+            token_owner_record,
+        )?),
+        (Some(voter_weight_record_info), Some(partner_realm), Some(partner_realm_config)) => {
+            Some(resolve_partner_voter_weight_record_membership(
+                registrar,
+                voter_weight_record_info,
+                partner_realm,
+                partner_realm_config,
+            )?)
+        }
+        (None, _, _) => return err!(RealmVoterError::MissingRealmMembership),
+        _ => return err!(RealmVoterError::PartnerRealmConfigRequired),
+    };
+
+    // Realm membership can only be omitted when the voter has input voter weight
+    require!(
+        membership.is_some() || ctx.accounts.input_voter_weight.is_some(),
+        RealmVoterError::MissingRealmMembership
+    );
+
+    let mut member_realms = vec![];
+
+    if let Some(membership) = membership {
+        membership.assert_is_valid(registrar, voter_weight_record)?;
+        member_realms.push(membership.realm);
+    }
+
+    if registrar.aggregate_realm_memberships {
+        for token_owner_record_info in ctx.remaining_accounts {
+            let membership =
+                resolve_token_owner_record_membership(registrar, token_owner_record_info)?;
+
+            membership.assert_is_valid(registrar, voter_weight_record)?;
+
+            // Several TokenOwnerRecords for the same Realm (ex. community and council) count as a single membership
+            if !member_realms.contains(&membership.realm) {
+                member_realms.push(membership.realm);
+            }
+        }
+    }
+
+    let realm_membership_voter_weight = registrar
+        .realm_member_voter_weight
+        .checked_mul(member_realms.len() as u64)
+        .ok_or(RealmVoterError::VoterWeightOverflow)?;
+
+    let current_slot = Clock::get()?.slot;
+
+    match &ctx.accounts.input_voter_weight {
+        Some(input_voter_weight) => {
+            let input_voter_weight_account = input_voter_weight.to_account_info();
+
+            let clone_record = voter_weight_record.clone();
+            let input_voter_weight_record =
+                resolve_input_voter_weight(&input_voter_weight_account, &clone_record, registrar)?;
 
-    // Note: We only verify a valid TokenOwnerRecord account exists for one of the configured spl-governance instances
+            // Setup voter_weight on top of the input voter weight
+            voter_weight_record.voter_weight = input_voter_weight_record
+                .get_voter_weight()
+                .checked_add(realm_membership_voter_weight)
+                .ok_or(RealmVoterError::VoterWeightOverflow)?;
+
+            // Realm membership is only valid as of the current slot
+            // and the input voter weight can't be extended beyond its own expiry
+            voter_weight_record.voter_weight_expiry =
+                input_voter_weight_record.get_voter_weight_expiry().map_or(
+                    Some(current_slot), // no previous expiry, use current slot
+                    |previous_expiry| Some(min(previous_expiry, current_slot)),
+                );
+
+            // Propagate action and target of the input voter weight
+            voter_weight_record.weight_action = input_voter_weight_record
+                .get_weight_action()
+                .map(|x| FromPrimitive::from_u32(x as u32).unwrap());
+            voter_weight_record.weight_action_target =
+                input_voter_weight_record.get_weight_action_target();
+        }
+        None => {
+            // The predecessor weight can't be skipped when the plugin is configured in a sequence
+            require!(
+                registrar.previous_voter_weight_plugin_program_id.is_none(),
+                RealmVoterError::MissingInputVoterWeight
+            );
+
+            // Setup voter_weight
+            voter_weight_record.voter_weight = realm_membership_voter_weight;
+
+            // Record is only valid as of the current slot
+            voter_weight_record.voter_weight_expiry = Some(current_slot);
+
+            // Set action and target to None to indicate the weight is valid for any action and target
+            voter_weight_record.weight_action = None;
+            voter_weight_record.weight_action_target = None;
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolves Realm membership from TokenOwnerRecord of one of the configured spl-governance instances
+fn resolve_token_owner_record_membership(
+    registrar: &Registrar,
+    token_owner_record_info: &AccountInfo,
+) -> Result<RealmMembership> {
+    let governance_program_id = token_owner_record_info.owner;
+
+    // Note: We verify a valid TokenOwnerRecord account exists for one of the configured spl-governance instances
     // The existence of the account proofs the governing_token_owner has interacted with spl-governance Realm at least once in the past
-    if !registrar
-        .governance_program_configs
-        .iter()
-        .any(|cc| cc.program_id == governance_program_id.key())
-    {
-        return err!(RealmVoterError::GovernanceProgramNotConfigured);
-    };
+    let governance_program_config =
+        registrar.get_governance_program_config(governance_program_id)?;
 
     let token_owner_record = token_owner_record::get_token_owner_record_data(
         governance_program_id,
-        &ctx.accounts.token_owner_record,
+        token_owner_record_info,
     )?;
 
-    // Ensure VoterWeightRecord and TokenOwnerRecord are for the same governing_token_owner
-    require_eq!(
-        token_owner_record.governing_token_owner,
-        voter_weight_record.governing_token_owner,
-        RealmVoterError::GoverningTokenOwnerMustMatch
+    // Dust deposits are not enough to be recognised as a member when the governance program config requires a minimum
+    require_gte!(
+        token_owner_record.governing_token_deposit_amount,
+        governance_program_config.min_governing_token_deposit_amount,
+        RealmVoterError::InsufficientGoverningTokenDeposit
     );
 
-    // Membership of the Realm the plugin is configured for is not allowed as a source of governance power
-    require_neq!(
-        token_owner_record.realm,
-        registrar.realm,
-        RealmVoterError::TokenOwnerRecordFromOwnRealmNotAllowed
+    Ok(RealmMembership {
+        realm: token_owner_record.realm,
+        governing_token_owner: token_owner_record.governing_token_owner,
+    })
+}
+
+/// Resolves Realm membership from VoterWeightRecord owned by the voter weight plugin configured for the partner Realm
+/// Any positive and not expired voter weight is treated as membership of the partner Realm
+fn resolve_partner_voter_weight_record_membership(
+    registrar: &Registrar,
+    voter_weight_record_info: &AccountInfo,
+    partner_realm_info: &AccountInfo,
+    partner_realm_config_info: &AccountInfo,
+) -> Result<RealmMembership> {
+    let governance_program_id = partner_realm_info.owner;
+
+    registrar.get_governance_program_config(governance_program_id)?;
+
+    let partner_realm = realm::get_realm_data(governance_program_id, partner_realm_info)?;
+
+    let partner_realm_config = realm_config::get_realm_config_data_for_realm(
+        governance_program_id,
+        partner_realm_config_info,
+        partner_realm_info.key,
+    )?;
+
+    let partner_voter_weight_record =
+        get_voter_weight_record_data(voter_weight_record_info.owner, voter_weight_record_info)?;
+
+    require_keys_eq!(
+        partner_voter_weight_record.realm,
+        *partner_realm_info.key,
+        RealmVoterError::InvalidPartnerVoterWeightRecordRealm
     );
 
-    // Setup voter_weight
-    voter_weight_record.voter_weight = registrar.realm_member_voter_weight;
+    // The plugin must be the one configured for the partner Realm and the governing token mint of the record
+    let token_config = partner_realm_config.get_token_config(
+        &partner_realm,
+        &partner_voter_weight_record.governing_token_mint,
+    )?;
+
+    require!(
+        token_config.voter_weight_addin == Some(*voter_weight_record_info.owner),
+        RealmVoterError::InvalidPartnerVoterWeightRecordPlugin
+    );
 
-    // Record is only valid as of the current slot
-    voter_weight_record.voter_weight_expiry = Some(Clock::get()?.slot);
+    if let Some(voter_weight_expiry) = partner_voter_weight_record.voter_weight_expiry {
+        require_gte!(
+            voter_weight_expiry,
+            Clock::get()?.slot,
+            RealmVoterError::PartnerVoterWeightRecordExpired
+        );
+    }
 
-    // Set action and target to None to indicate the weight is valid for any action and target
-    voter_weight_record.weight_action = None;
-    voter_weight_record.weight_action_target = None;
+    require_gt!(
+        partner_voter_weight_record.voter_weight,
+        0,
+        RealmVoterError::PartnerVoterWeightRecordWithoutWeight
+    );
 
-    Ok(())
+    Ok(RealmMembership {
+        realm: partner_voter_weight_record.realm,
+        governing_token_owner: partner_voter_weight_record.governing_token_owner,
+    })
 }
@@ -20,9 +20,21 @@ pub mod realm_voter {
     pub fn create_registrar(
         ctx: Context<CreateRegistrar>,
         max_governance_programs: u8,
+        use_previous_voter_weight_plugin: bool,
     ) -> Result<()> {
         log_version();
-        instructions::create_registrar(ctx, max_governance_programs)
+        instructions::create_registrar(
+            ctx,
+            max_governance_programs,
+            use_previous_voter_weight_plugin,
+        )
+    }
+    pub fn resize_registrar(
+        ctx: Context<ResizeRegistrar>,
+        max_governance_programs: u8,
+    ) -> Result<()> {
+        log_version();
+        instructions::resize_registrar(ctx, max_governance_programs)
     }
     pub fn create_voter_weight_record(
         ctx: Context<CreateVoterWeightRecord>,
@@ -43,17 +55,28 @@ pub mod realm_voter {
         ctx: Context<ConfigureVoterWeights>,
         realm_member_voter_weight: u64,
         max_voter_weight: u64,
+        aggregate_realm_memberships: bool,
     ) -> Result<()> {
         log_version();
-        instructions::configure_voter_weights(ctx, realm_member_voter_weight, max_voter_weight)
+        instructions::configure_voter_weights(
+            ctx,
+            realm_member_voter_weight,
+            max_voter_weight,
+            aggregate_realm_memberships,
+        )
     }
 
     pub fn configure_governance_program(
         ctx: Context<ConfigureGovernanceProgram>,
         change_type: CollectionItemChangeType,
+        min_governing_token_deposit_amount: u64,
     ) -> Result<()> {
         log_version();
-        instructions::configure_governance_program(ctx, change_type)
+        instructions::configure_governance_program(
+            ctx,
+            change_type,
+            min_governing_token_deposit_amount,
+        )
     }
 }
 
@@ -6,6 +6,8 @@ pub struct GovernanceProgramConfig {
     /// The program id of the configured spl-governance instance
     pub program_id: Pubkey,
 
-    /// Reserved for future upgrades
-    pub reserved: [u8; 8],
+    /// Minimum TokenOwnerRecord.governing_token_deposit_amount required to be recognised as a member
+    /// of a Realm from the configured spl-governance instance
+    /// Setting it to 0 accepts any existing TokenOwnerRecord regardless of its deposit
+    pub min_governing_token_deposit_amount: u64,
 }
@@ -1,5 +1,8 @@
-use crate::{id, state::GovernanceProgramConfig, tools::anchor::DISCRIMINATOR_SIZE};
+use crate::{
+    error::RealmVoterError, id, state::GovernanceProgramConfig, tools::anchor::DISCRIMINATOR_SIZE,
+};
 use anchor_lang::prelude::*;
+use gpl_shared::compose::RegistrarBase;
 use solana_program::pubkey::PUBKEY_BYTES;
 
 /// Enum defining collection item change type
@@ -41,8 +44,18 @@ pub struct Registrar {
     /// Note: This is not a security vulnerability because the plugin is inherently not secure and used only to encourage DAO usage and registration of spl-governance instances
     pub max_voter_weight: u64,
 
+    /// Indicates whether memberships of several Realms are aggregated into the voter weight
+    /// When enabled each distinct Realm the voter is a member of adds realm_member_voter_weight to the voter weight
+    /// and max_voter_weight should be configured to account for the total of all the memberships
+    pub aggregate_realm_memberships: bool,
+
+    /// If the plugin is one in a sequence, this is the previous plugin program ID
+    /// If set, then update_voter_weight_record will expect a voter_weight_record owned by this program
+    /// and realm membership weight is added on top of the predecessor weight
+    pub previous_voter_weight_plugin_program_id: Option<Pubkey>,
+
     /// Reserved for future upgrades
-    pub reserved: [u8; 128],
+    pub reserved: [u8; 94],
 }
 
 impl Registrar {
@@ -53,7 +66,38 @@ impl Registrar {
             + max_governance_programs as usize * (PUBKEY_BYTES + 8)
             + 8
             + 8
-            + 128
+            + 1
+            + (PUBKEY_BYTES + 1)
+            + 94
+    }
+
+    /// Returns GovernanceProgramConfig for the given spl-governance instance or an error if it's not configured
+    pub fn get_governance_program_config(
+        &self,
+        governance_program_id: &Pubkey,
+    ) -> Result<&GovernanceProgramConfig> {
+        self.governance_program_configs
+            .iter()
+            .find(|cc| cc.program_id == *governance_program_id)
+            .ok_or_else(|| error!(RealmVoterError::GovernanceProgramNotConfigured))
+    }
+}
+
+impl<'a> RegistrarBase<'a> for Registrar {
+    fn get_realm(&'a self) -> &'a Pubkey {
+        &self.realm
+    }
+
+    fn get_governance_program_id(&'a self) -> &'a Pubkey {
+        &self.governance_program_id
+    }
+
+    fn get_governing_token_mint(&'a self) -> &'a Pubkey {
+        &self.governing_token_mint
+    }
+
+    fn get_previous_voter_weight_plugin_program_id(&'a self) -> &'a Option<Pubkey> {
+        &self.previous_voter_weight_plugin_program_id
     }
 }
 
@@ -89,9 +133,11 @@ mod test {
                 GovernanceProgramConfig::default(),
                 GovernanceProgramConfig::default(),
             ],
-            reserved: [0; 128],
+            reserved: [0; 94],
             max_voter_weight: 100,
             realm_member_voter_weight: 10,
+            aggregate_realm_memberships: true,
+            previous_voter_weight_plugin_program_id: Pubkey::default().into(),
         };
 
         // Act
@@ -3,7 +3,7 @@ use program_test::realm_voter_test::RealmVoterTest;
 use solana_program_test::*;
 use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
 mod program_test;
-use program_test::tools::{assert_anchor_err, assert_realm_voter_err};
+use program_test::tools::{assert_anchor_err, assert_realm_voter_err, NopOverride};
 
 #[tokio::test]
 async fn test_configure_governance_program() -> Result<(), TransportError> {
@@ -126,6 +126,52 @@ async fn test_configure_governance_program_for_existing_program() -> Result<(),
     Ok(())
 }
 
+#[tokio::test]
+async fn test_configure_governance_program_with_min_governing_token_deposit_amount(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .configure_governance_program_using_ix(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+            100,
+            NopOverride,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.governance_program_configs.len(), 1);
+    assert_eq!(
+        registrar.governance_program_configs[0].min_governing_token_deposit_amount,
+        100
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_configure_governance_program_with_invalid_realm_error() -> Result<(), TransportError>
 {
@@ -147,6 +193,7 @@ async fn test_configure_governance_program_with_invalid_realm_error() -> Result<
             &registrar_cookie,
             &governance_program_cookie,
             CollectionItemChangeType::Upsert,
+            0,
             |i| i.accounts[1].pubkey = realm_cookie2.address, // realm
             None,
         )
@@ -179,6 +226,7 @@ async fn test_configure_governance_program_with_realm_authority_must_sign_error(
             &registrar_cookie,
             &governance_program_cookie,
             CollectionItemChangeType::Upsert,
+            0,
             |i| i.accounts[2].is_signer = false, // realm_authority
             Some(&[]),
         )
@@ -213,6 +261,7 @@ async fn test_configure_governance_program_with_invalid_realm_authority_error(
             &registrar_cookie,
             &governance_program_cookie,
             CollectionItemChangeType::Upsert,
+            0,
             |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
             Some(&[&realm_authority]),
         )
@@ -67,6 +67,51 @@ async fn test_configure_voter_weights() -> Result<(), TransportError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_configure_voter_weights_with_aggregate_realm_memberships(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    realm_voter_test
+        .configure_voter_weights_using_ix(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            330,
+            true,
+            NopOverride,
+            None,
+        )
+        .await?;
+
+    // Assert
+
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert!(registrar.aggregate_realm_memberships);
+    assert_eq!(registrar.realm_member_voter_weight, 10);
+
+    let max_voter_weight_record = realm_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 330);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_configure_voter_weights_with_invalid_realm_error() -> Result<(), TransportError> {
     // Arrange
@@ -90,6 +135,7 @@ async fn test_configure_voter_weights_with_invalid_realm_error() -> Result<(), T
             &mut max_voter_weight_record_cookie,
             10,
             110,
+            false,
             |i| i.accounts[1].pubkey = realm_cookie2.address, // realm
             None,
         )
@@ -125,6 +171,7 @@ async fn test_configure_voter_weights_with_realm_authority_must_sign_error(
             &mut max_voter_weight_record_cookie,
             10,
             110,
+            false,
             |i| i.accounts[2].is_signer = false, // realm_authority
             Some(&[]),
         )
@@ -162,6 +209,7 @@ async fn test_configure_voter_weights_with_invalid_realm_authority_error(
             &mut max_voter_weight_record_cookie,
             10,
             110,
+            false,
             |i| i.accounts[2].pubkey = realm_authority.pubkey(), // realm_authority
             Some(&[&realm_authority]),
         )
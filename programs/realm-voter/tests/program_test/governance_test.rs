@@ -362,6 +362,65 @@ impl GovernanceTest {
         })
     }
 
+    #[allow(dead_code)]
+    pub async fn with_token_owner_deposit(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        token_owner_cookie: &WalletCookie,
+        amount: u64,
+    ) -> Result<TokenOwnerRecordCookie, TransportError> {
+        let token_owner_record_key = get_token_owner_record_address(
+            &self.program_id,
+            &realm_cookie.address,
+            &realm_cookie.account.community_mint,
+            &token_owner_cookie.address,
+        );
+
+        let governing_token_account_cookie = self
+            .bench
+            .with_tokens(
+                &realm_cookie.community_mint_cookie,
+                &token_owner_cookie.address,
+                amount,
+            )
+            .await?;
+
+        let deposit_ix = deposit_governing_tokens(
+            &self.program_id,
+            &realm_cookie.address,
+            &governing_token_account_cookie.address,
+            &token_owner_cookie.address,
+            &token_owner_cookie.address,
+            &self.bench.payer.pubkey(),
+            amount,
+            &realm_cookie.account.community_mint,
+        );
+
+        self.bench
+            .process_transaction(&[deposit_ix], Some(&[&token_owner_cookie.signer]))
+            .await?;
+
+        let account = TokenOwnerRecordV2 {
+            account_type: GovernanceAccountType::TokenOwnerRecordV2,
+            realm: realm_cookie.address,
+            governing_token_mint: realm_cookie.account.community_mint,
+            governing_token_owner: token_owner_cookie.address,
+            governing_token_deposit_amount: amount,
+            unrelinquished_votes_count: 0,
+
+            outstanding_proposal_count: 0,
+            reserved: [0; 6],
+            governance_delegate: None,
+            reserved_v2: [0; 128],
+            version: TOKEN_OWNER_RECORD_LAYOUT_VERSION,
+        };
+
+        Ok(TokenOwnerRecordCookie {
+            address: token_owner_record_key,
+            account,
+        })
+    }
+
     #[allow(dead_code)]
     pub async fn relinquish_vote(
         &mut self,
@@ -8,9 +8,10 @@ use gpl_realm_voter::state::max_voter_weight_record::{
 use gpl_realm_voter::state::*;
 
 use solana_program_test::{BanksClientError, ProgramTest};
-use solana_sdk::instruction::Instruction;
+use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
+use spl_governance::state::realm_config::get_realm_config_address;
 
 use crate::program_test::governance_test::GovernanceTest;
 use crate::program_test::program_test_bench::ProgramTestBench;
@@ -118,6 +119,7 @@ impl RealmVoterTest {
         let data =
             anchor_lang::InstructionData::data(&gpl_realm_voter::instruction::CreateRegistrar {
                 max_governance_programs,
+                use_previous_voter_weight_plugin: false,
             });
 
         let accounts = anchor_lang::ToAccountMetas::to_account_metas(
@@ -153,9 +155,11 @@ impl RealmVoterTest {
             realm: realm_cookie.address,
             governing_token_mint: realm_cookie.account.community_mint,
             governance_program_configs: vec![],
-            reserved: [0; 128],
+            reserved: [0; 94],
             max_voter_weight: 0,
             realm_member_voter_weight: 0,
+            aggregate_realm_memberships: false,
+            previous_voter_weight_plugin_program_id: None,
         };
 
         Ok(RegistrarCookie {
@@ -166,6 +170,117 @@ impl RealmVoterTest {
         })
     }
 
+    #[allow(dead_code)]
+    pub async fn with_registrar_using_previous_voter_weight_plugin(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        previous_voter_weight_plugin_program_id: &Pubkey,
+    ) -> Result<RegistrarCookie, BanksClientError> {
+        let mut registrar_cookie = self
+            .with_registrar_using_ix(
+                realm_cookie,
+                |i| {
+                    i.data = anchor_lang::InstructionData::data(
+                        &gpl_realm_voter::instruction::CreateRegistrar {
+                            max_governance_programs: 10,
+                            use_previous_voter_weight_plugin: true,
+                        },
+                    );
+                    i.accounts.push(AccountMeta::new_readonly(
+                        *previous_voter_weight_plugin_program_id,
+                        false,
+                    ));
+                },
+                None,
+            )
+            .await?;
+
+        registrar_cookie
+            .account
+            .previous_voter_weight_plugin_program_id =
+            Some(*previous_voter_weight_plugin_program_id);
+
+        Ok(registrar_cookie)
+    }
+
+    /// Creates VoterWeightRecord owned by the previous voter weight plugin
+    #[allow(dead_code)]
+    pub async fn with_previous_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &WalletCookie,
+        previous_voter_weight_plugin_program_id: &Pubkey,
+        voter_weight: u64,
+    ) -> Pubkey {
+        let address = Pubkey::new_unique();
+
+        let account = VoterWeightRecord {
+            realm: registrar_cookie.account.realm,
+            governing_token_mint: registrar_cookie.account.governing_token_mint,
+            governing_token_owner: voter_cookie.address,
+            voter_weight,
+            voter_weight_expiry: None,
+            weight_action: None,
+            weight_action_target: None,
+            reserved: [0; 8],
+        };
+
+        let mut data = vec![];
+        anchor_lang::AccountSerialize::try_serialize(&account, &mut data).unwrap();
+
+        let mut previous_voter_weight_record = solana_sdk::account::Account::new(
+            self.bench.rent.minimum_balance(data.len()),
+            data.len(),
+            previous_voter_weight_plugin_program_id,
+        );
+        previous_voter_weight_record.data = data;
+
+        self.bench
+            .context
+            .borrow_mut()
+            .set_account(&address, &previous_voter_weight_record.into());
+
+        address
+    }
+
+    #[allow(dead_code)]
+    pub async fn resize_registrar(
+        &mut self,
+        registrar_cookie: &RegistrarCookie,
+        max_governance_programs: u8,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_realm_voter::instruction::ResizeRegistrar {
+                max_governance_programs,
+            });
+
+        let accounts = anchor_lang::ToAccountMetas::to_account_metas(
+            &gpl_realm_voter::accounts::ResizeRegistrar {
+                registrar: registrar_cookie.address,
+                realm: registrar_cookie.account.realm,
+                governance_program_id: self.governance.program_id,
+                governing_token_mint: registrar_cookie.account.governing_token_mint,
+                realm_authority: registrar_cookie.realm_authority.pubkey(),
+                payer: self.bench.payer.pubkey(),
+                system_program: solana_sdk::system_program::id(),
+            },
+            None,
+        );
+
+        let resize_registrar_ix = Instruction {
+            program_id: gpl_realm_voter::id(),
+            accounts,
+            data,
+        };
+
+        self.bench
+            .process_transaction(
+                &[resize_registrar_ix],
+                Some(&[&registrar_cookie.realm_authority]),
+            )
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn with_voter_weight_record(
         &self,
@@ -308,7 +423,116 @@ impl RealmVoterTest {
         let accounts = gpl_realm_voter::accounts::UpdateVoterWeightRecord {
             registrar: registrar_cookie.address,
             voter_weight_record: voter_weight_record_cookie.address,
-            token_owner_record: token_owner_record_cookie.address,
+            token_owner_record: Some(token_owner_record_cookie.address),
+            partner_realm: None,
+            partner_realm_config: None,
+            input_voter_weight: None,
+        };
+
+        let account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        let instructions = vec![Instruction {
+            program_id: gpl_realm_voter::id(),
+            accounts: account_metas,
+            data,
+        }];
+
+        self.bench.process_transaction(&instructions, None).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record_with_input_voter_weight(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &mut VoterWeightRecordCookie,
+        token_owner_record_cookie: Option<&TokenOwnerRecordCookie>,
+        input_voter_weight: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_realm_voter::instruction::UpdateVoterWeightRecord {},
+        );
+
+        let accounts = gpl_realm_voter::accounts::UpdateVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+            token_owner_record: token_owner_record_cookie.map(|cookie| cookie.address),
+            partner_realm: None,
+            partner_realm_config: None,
+            input_voter_weight: Some(*input_voter_weight),
+        };
+
+        let account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        let instructions = vec![Instruction {
+            program_id: gpl_realm_voter::id(),
+            accounts: account_metas,
+            data,
+        }];
+
+        self.bench.process_transaction(&instructions, None).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record_with_token_owner_records(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &mut VoterWeightRecordCookie,
+        token_owner_record_cookie: &TokenOwnerRecordCookie,
+        other_token_owner_record_cookies: &[&TokenOwnerRecordCookie],
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_realm_voter::instruction::UpdateVoterWeightRecord {},
+        );
+
+        let accounts = gpl_realm_voter::accounts::UpdateVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+            token_owner_record: Some(token_owner_record_cookie.address),
+            partner_realm: None,
+            partner_realm_config: None,
+            input_voter_weight: None,
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        for token_owner_record_cookie in other_token_owner_record_cookies {
+            account_metas.push(AccountMeta::new_readonly(
+                token_owner_record_cookie.address,
+                false,
+            ));
+        }
+
+        let instructions = vec![Instruction {
+            program_id: gpl_realm_voter::id(),
+            accounts: account_metas,
+            data,
+        }];
+
+        self.bench.process_transaction(&instructions, None).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record_with_partner_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &mut VoterWeightRecordCookie,
+        partner_realm_cookie: &RealmCookie,
+        partner_voter_weight_record_cookie: &VoterWeightRecordCookie,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_realm_voter::instruction::UpdateVoterWeightRecord {},
+        );
+
+        let partner_realm_config =
+            get_realm_config_address(&self.governance.program_id, &partner_realm_cookie.address);
+
+        let accounts = gpl_realm_voter::accounts::UpdateVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+            token_owner_record: Some(partner_voter_weight_record_cookie.address),
+            partner_realm: Some(partner_realm_cookie.address),
+            partner_realm_config: Some(partner_realm_config),
+            input_voter_weight: None,
         };
 
         let account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
@@ -335,6 +559,7 @@ impl RealmVoterTest {
             max_voter_weight_record_cookie,
             realm_member_voter_weight,
             max_voter_weight,
+            false,
             NopOverride,
             None,
         )
@@ -348,6 +573,7 @@ impl RealmVoterTest {
         max_voter_weight_record_cookie: &mut MaxVoterWeightRecordCookie,
         realm_member_voter_weight: u64,
         max_voter_weight: u64,
+        aggregate_realm_memberships: bool,
         instruction_override: F,
         signers_override: Option<&[&Keypair]>,
     ) -> Result<(), BanksClientError> {
@@ -355,6 +581,7 @@ impl RealmVoterTest {
             &gpl_realm_voter::instruction::ConfigureVoterWeights {
                 max_voter_weight,
                 realm_member_voter_weight,
+                aggregate_realm_memberships,
             },
         );
 
@@ -393,6 +620,7 @@ impl RealmVoterTest {
             registrar_cookie,
             governance_program_cookie,
             change_type,
+            0,
             NopOverride,
             None,
         )
@@ -405,11 +633,15 @@ impl RealmVoterTest {
         registrar_cookie: &RegistrarCookie,
         governance_program_cookie: &GovernanceProgramCookie,
         change_type: CollectionItemChangeType,
+        min_governing_token_deposit_amount: u64,
         instruction_override: F,
         signers_override: Option<&[&Keypair]>,
     ) -> Result<GovernanceProgramConfigCookie, BanksClientError> {
         let data = anchor_lang::InstructionData::data(
-            &gpl_realm_voter::instruction::ConfigureGovernanceProgram { change_type },
+            &gpl_realm_voter::instruction::ConfigureGovernanceProgram {
+                change_type,
+                min_governing_token_deposit_amount,
+            },
         );
 
         let accounts = gpl_realm_voter::accounts::ConfigureGovernanceProgram {
@@ -436,7 +668,7 @@ impl RealmVoterTest {
 
         let governance_program_config = GovernanceProgramConfig {
             program_id: governance_program_cookie.program_id.clone(),
-            reserved: [0; 8],
+            min_governing_token_deposit_amount,
         };
 
         Ok(GovernanceProgramConfigCookie {
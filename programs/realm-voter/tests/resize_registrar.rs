@@ -0,0 +1,117 @@
+use gpl_realm_voter::{
+    error::RealmVoterError,
+    state::{CollectionItemChangeType, Registrar},
+};
+use program_test::realm_voter_test::RealmVoterTest;
+use program_test::tools::assert_realm_voter_err;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_resize_registrar() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Act
+    realm_voter_test
+        .resize_registrar(&registrar_cookie, 20)
+        .await?;
+
+    // Assert
+    let registrar_account = realm_voter_test
+        .bench
+        .get_account(&registrar_cookie.address)
+        .await
+        .unwrap();
+
+    assert_eq!(registrar_account.data.len(), Registrar::get_space(20));
+
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar, registrar_cookie.account);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resize_registrar_to_configured_governance_programs() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .resize_registrar(&registrar_cookie, 1)
+        .await?;
+
+    // Assert
+    let registrar_account = realm_voter_test
+        .bench
+        .get_account(&registrar_cookie.address)
+        .await
+        .unwrap();
+
+    assert_eq!(registrar_account.data.len(), Registrar::get_space(1));
+
+    let registrar = realm_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.governance_program_configs.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resize_registrar_below_configured_governance_programs_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .resize_registrar(&registrar_cookie, 0)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, RealmVoterError::InvalidResizeMaxGovernancePrograms);
+
+    Ok(())
+}
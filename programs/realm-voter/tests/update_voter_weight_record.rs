@@ -2,6 +2,7 @@ use crate::program_test::realm_voter_test::RealmVoterTest;
 use gpl_realm_voter::{error::RealmVoterError, state::CollectionItemChangeType};
 use program_test::tools::*;
 use solana_program_test::*;
+use solana_sdk::pubkey::Pubkey;
 use solana_sdk::transport::TransportError;
 mod program_test;
 
@@ -212,3 +213,831 @@ async fn test_update_voter_weight_record_with_token_owner_record_must_match_erro
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_min_governing_token_deposit_amount(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord with deposit for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_deposit(&realm_cookie2, &token_owner_cookie, 100)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program_using_ix(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+            100,
+            NopOverride,
+            None,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await?;
+
+    // Assert
+
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 10);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_insufficient_governing_token_deposit_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecord with dust deposit for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_deposit(&realm_cookie2, &token_owner_cookie, 1)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program_using_ix(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+            100,
+            NopOverride,
+            None,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+
+    assert_realm_voter_err(err, RealmVoterError::InsufficientGoverningTokenDeposit);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_partner_voter_weight_record(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Partner Realm uses the realm voter plugin and grants its members weight via VoterWeightRecord
+    let partner_realm_cookie = realm_voter_test.governance.with_realm().await?;
+    let partner_registrar_cookie = realm_voter_test
+        .with_registrar(&partner_realm_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_governance_program(
+            &partner_registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            5,
+            50,
+        )
+        .await?;
+
+    let mut partner_max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&partner_registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &partner_registrar_cookie,
+            &mut partner_max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // Create TokenOwnerRecord for a third Realm which gives membership of the partner Realm
+    let realm_cookie3 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie3, &token_owner_cookie)
+        .await?;
+
+    let mut partner_voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&partner_registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    realm_voter_test
+        .update_voter_weight_record(
+            &partner_registrar_cookie,
+            &mut partner_voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record_with_partner_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &partner_realm_cookie,
+            &partner_voter_weight_record_cookie,
+        )
+        .await?;
+
+    // Assert
+
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 5);
+    assert_eq!(voter_weight_record.weight_action, None);
+    assert_eq!(voter_weight_record.weight_action_target, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_expired_partner_voter_weight_record_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let partner_realm_cookie = realm_voter_test.governance.with_realm().await?;
+    let partner_registrar_cookie = realm_voter_test
+        .with_registrar(&partner_realm_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_governance_program(
+            &partner_registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut partner_max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&partner_registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &partner_registrar_cookie,
+            &mut partner_max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    let realm_cookie3 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie3, &token_owner_cookie)
+        .await?;
+
+    let mut partner_voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&partner_registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    realm_voter_test
+        .update_voter_weight_record(
+            &partner_registrar_cookie,
+            &mut partner_voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    realm_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record_with_partner_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &partner_realm_cookie,
+            &partner_voter_weight_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+
+    assert_realm_voter_err(err, RealmVoterError::PartnerVoterWeightRecordExpired);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_partner_voter_weight_record_without_weight_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let partner_realm_cookie = realm_voter_test.governance.with_realm().await?;
+    let partner_registrar_cookie = realm_voter_test
+        .with_registrar(&partner_realm_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    realm_voter_test
+        .configure_governance_program(
+            &partner_registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    // Partner realm_member_voter_weight is not configured and stays 0
+
+    let realm_cookie3 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie3, &token_owner_cookie)
+        .await?;
+
+    let mut partner_voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&partner_registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    realm_voter_test
+        .update_voter_weight_record(
+            &partner_registrar_cookie,
+            &mut partner_voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record_with_partner_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &partner_realm_cookie,
+            &partner_voter_weight_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+
+    assert_realm_voter_err(err, RealmVoterError::PartnerVoterWeightRecordWithoutWeight);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_aggregated_realm_memberships(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecords for two other Realms
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie2 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let realm_cookie3 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie3 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie3, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights_using_ix(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+            true,
+            NopOverride,
+            None,
+        )
+        .await?;
+
+    // Act
+
+    // TokenOwnerRecord of Realm #2 is provided twice and should count only once
+    realm_voter_test
+        .update_voter_weight_record_with_token_owner_records(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie2,
+            &[&token_owner_record_cookie3, &token_owner_record_cookie2],
+        )
+        .await?;
+
+    // Assert
+
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 20);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_aggregated_realm_memberships_overflow_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    // Create TokenOwnerRecords for two other Realms
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie2 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let realm_cookie3 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie3 = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie3, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights_using_ix(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            u64::MAX,
+            u64::MAX,
+            true,
+            NopOverride,
+            None,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record_with_token_owner_records(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie2,
+            &[&token_owner_record_cookie3],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+
+    assert_realm_voter_err(err, RealmVoterError::VoterWeightOverflow);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_input_voter_weight() -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test.with_registrar(&realm_cookie).await?;
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+
+    // Create TokenOwnerRecord with deposit for the Realm itself which is used as input voter weight
+    let input_token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_deposit(&realm_cookie, &token_owner_cookie, 50)
+        .await?;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    let clock = realm_voter_test.bench.get_clock().await;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record_with_input_voter_weight(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            Some(&token_owner_record_cookie),
+            &input_token_owner_record_cookie.address,
+        )
+        .await?;
+
+    // Assert
+
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 60);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+    assert_eq!(voter_weight_record.weight_action, None);
+    assert_eq!(voter_weight_record.weight_action_target, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_previous_voter_weight_plugin(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let previous_voter_weight_plugin_program_id = Pubkey::new_unique();
+
+    let registrar_cookie = realm_voter_test
+        .with_registrar_using_previous_voter_weight_plugin(
+            &realm_cookie,
+            &previous_voter_weight_plugin_program_id,
+        )
+        .await?;
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+
+    // Create VoterWeightRecord of the previous plugin which is used as input voter weight
+    let previous_voter_weight_record = realm_voter_test
+        .with_previous_voter_weight_record(
+            &registrar_cookie,
+            &token_owner_cookie,
+            &previous_voter_weight_plugin_program_id,
+            50,
+        )
+        .await;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    let clock = realm_voter_test.bench.get_clock().await;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record_with_input_voter_weight(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            Some(&token_owner_record_cookie),
+            &previous_voter_weight_record,
+        )
+        .await?;
+
+    // Assert
+
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 60);
+    assert_eq!(voter_weight_record.voter_weight_expiry, Some(clock.slot));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_previous_voter_weight_plugin_and_no_membership(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let previous_voter_weight_plugin_program_id = Pubkey::new_unique();
+
+    let registrar_cookie = realm_voter_test
+        .with_registrar_using_previous_voter_weight_plugin(
+            &realm_cookie,
+            &previous_voter_weight_plugin_program_id,
+        )
+        .await?;
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+
+    let previous_voter_weight_record = realm_voter_test
+        .with_previous_voter_weight_record(
+            &registrar_cookie,
+            &token_owner_cookie,
+            &previous_voter_weight_plugin_program_id,
+            50,
+        )
+        .await;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let mut max_voter_weight_record_cookie = realm_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    realm_voter_test
+        .configure_voter_weights(
+            &registrar_cookie,
+            &mut max_voter_weight_record_cookie,
+            10,
+            110,
+        )
+        .await?;
+
+    // Act
+    realm_voter_test
+        .update_voter_weight_record_with_input_voter_weight(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            None,
+            &previous_voter_weight_record,
+        )
+        .await?;
+
+    // Assert
+
+    // The voter isn't a member of any other Realm and only the input voter weight is passed through
+    let voter_weight_record = realm_voter_test
+        .get_voter_weight_record(&voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 50);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_missing_input_voter_weight_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = realm_voter_test
+        .with_registrar_using_previous_voter_weight_plugin(&realm_cookie, &Pubkey::new_unique())
+        .await?;
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+
+    // Create TokenOwnerRecord for other Realm
+    let realm_cookie2 = realm_voter_test.governance.with_realm().await?;
+    let token_owner_record_cookie = realm_voter_test
+        .governance
+        .with_token_owner_record(&realm_cookie2, &token_owner_cookie)
+        .await?;
+
+    let governance_program_cookie = realm_voter_test.with_governance_program(None).await;
+
+    realm_voter_test
+        .configure_governance_program(
+            &registrar_cookie,
+            &governance_program_cookie,
+            CollectionItemChangeType::Upsert,
+        )
+        .await?;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &token_owner_record_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+
+    assert_realm_voter_err(err, RealmVoterError::MissingInputVoterWeight);
+
+    Ok(())
+}
@@ -214,6 +214,28 @@ impl ProgramTestBench {
             .await
     }
 
+    #[allow(dead_code)]
+    pub async fn transfer_tokens(
+        &self,
+        source: &Pubkey,
+        destination: &Pubkey,
+        owner: &Keypair,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let transfer_instruction = spl_token::instruction::transfer(
+            &spl_token::id(),
+            source,
+            destination,
+            &owner.pubkey(),
+            &[],
+            amount,
+        )
+        .unwrap();
+
+        self.process_transaction(&[transfer_instruction], Some(&[owner]))
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn create_token_account(
         &self,
@@ -6,7 +6,7 @@ use gpl_token_haver::state::max_voter_weight_record::MaxVoterWeightRecord;
 use gpl_token_haver::state::*;
 
 use solana_program_test::{BanksClientError, ProgramTest};
-use solana_sdk::instruction::Instruction;
+use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 
@@ -26,7 +26,7 @@ pub struct RegistrarCookie {
     pub account: Registrar,
 
     pub realm_authority: Keypair,
-    pub mints: Vec<Pubkey>,
+    pub mints: Vec<MintConfig>,
 }
 
 pub struct VoterWeightRecordCookie {
@@ -39,6 +39,10 @@ pub struct MaxVoterWeightRecordCookie {
     pub account: MaxVoterWeightRecord,
 }
 
+pub struct TokenHoldRecordCookie {
+    pub address: Pubkey,
+}
+
 pub struct GovernanceProgramCookie {
     pub program_id: Pubkey,
 }
@@ -103,15 +107,53 @@ impl RealmVoterTest {
         realm_cookie: &RealmCookie,
         instruction_override: F,
         signers_override: Option<&[&Keypair]>,
+    ) -> Result<RegistrarCookie, BanksClientError> {
+        let mints = vec![MintConfig {
+            mint: realm_cookie.account.community_mint,
+            weight_mode: MintWeightMode::Fixed,
+            weight: 1_000_000,
+            digit_shift: 0,
+            lock_policy: LockPolicy::Frozen,
+            min_hold_slots: 0,
+            min_balance: 0,
+        }];
+
+        self.with_registrar_using_mints_and_ix(
+            realm_cookie,
+            &mints,
+            instruction_override,
+            signers_override,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_registrar_using_mints(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        mints: &[MintConfig],
+    ) -> Result<RegistrarCookie, BanksClientError> {
+        self.with_registrar_using_mints_and_ix(realm_cookie, mints, NopOverride, None)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn with_registrar_using_mints_and_ix<F: Fn(&mut Instruction)>(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        mints: &[MintConfig],
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
     ) -> Result<RegistrarCookie, BanksClientError> {
         let registrar_key =
             get_registrar_address(&realm_cookie.address, &realm_cookie.account.community_mint);
 
-        let mints = &[realm_cookie.account.community_mint].to_vec();
+        let mints = &mints.to_vec();
 
         let data =
             anchor_lang::InstructionData::data(&gpl_token_haver::instruction::CreateRegistrar {
                 mints: mints.clone(),
+                lock_programs: vec![],
             });
 
         let accounts = anchor_lang::ToAccountMetas::to_account_metas(
@@ -119,6 +161,7 @@ impl RealmVoterTest {
                 registrar: registrar_key,
                 realm: realm_cookie.address,
                 governance_program_id: self.governance.program_id,
+                previous_voter_weight_plugin_program_id: None,
                 governing_token_mint: realm_cookie.account.community_mint,
                 realm_authority: realm_cookie.get_realm_authority().pubkey(),
                 payer: self.bench.payer.pubkey(),
@@ -146,7 +189,10 @@ impl RealmVoterTest {
             governance_program_id: self.governance.program_id,
             realm: realm_cookie.address,
             governing_token_mint: realm_cookie.account.community_mint,
-            mints: mints.clone(),
+            mints: vec![],
+            mint_configs: mints.clone(),
+            lock_programs: vec![],
+            previous_voter_weight_plugin_program_id: None,
         };
 
         Ok(RegistrarCookie {
@@ -242,6 +288,7 @@ impl RealmVoterTest {
         let accounts = gpl_token_haver::accounts::UpdateVoterWeightRecord {
             registrar: registrar_cookie.address,
             voter_weight_record: voter_weight_record_cookie.address,
+            input_voter_weight: Some(token_owner_record_cookie.address),
         };
 
         let account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
@@ -255,6 +302,88 @@ impl RealmVoterTest {
         self.bench.process_transaction(&instructions, None).await
     }
 
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record_using_token_accounts(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_weight_record_cookie: &mut VoterWeightRecordCookie,
+        token_accounts: &[(Pubkey, Option<Pubkey>)],
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_token_haver::instruction::UpdateVoterWeightRecord {},
+        );
+
+        let accounts = gpl_token_haver::accounts::UpdateVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            voter_weight_record: voter_weight_record_cookie.address,
+            input_voter_weight: None,
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        // Each token account is followed by its lock account
+        for (token_account, lock_account) in token_accounts {
+            account_metas.push(AccountMeta::new_readonly(*token_account, false));
+
+            if let Some(lock_account) = lock_account {
+                account_metas.push(AccountMeta::new_readonly(*lock_account, false));
+            }
+        }
+
+        let instructions = vec![Instruction {
+            program_id: gpl_token_haver::id(),
+            accounts: account_metas,
+            data,
+        }];
+
+        self.bench.process_transaction(&instructions, None).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_token_hold_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        token_owner_cookie: &WalletCookie,
+    ) -> Result<TokenHoldRecordCookie, BanksClientError> {
+        let token_hold_record_key = get_token_hold_record_address(
+            &registrar_cookie.address,
+            &token_owner_cookie.address,
+            mint,
+        );
+
+        let data = anchor_lang::InstructionData::data(
+            &gpl_token_haver::instruction::UpdateTokenHoldRecord {},
+        );
+
+        let accounts = gpl_token_haver::accounts::UpdateTokenHoldRecord {
+            registrar: registrar_cookie.address,
+            token_account: *token_account,
+            governing_token_owner: Some(token_owner_cookie.address),
+            token_hold_record: token_hold_record_key,
+            payer: self.bench.payer.pubkey(),
+            system_program: solana_sdk::system_program::id(),
+        };
+
+        let update_token_hold_record_ix = Instruction {
+            program_id: gpl_token_haver::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        self.bench
+            .process_transaction(
+                &[update_token_hold_record_ix],
+                Some(&[&token_owner_cookie.signer]),
+            )
+            .await?;
+
+        Ok(TokenHoldRecordCookie {
+            address: token_hold_record_key,
+        })
+    }
+
     #[allow(dead_code)]
     pub async fn configure_voter_weights(
         &self,
@@ -1,5 +1,8 @@
 use crate::program_test::token_haver_test::RealmVoterTest;
-use gpl_token_haver::{error::TokenHaverError, state::CollectionItemChangeType};
+use gpl_token_haver::{
+    error::TokenHaverError,
+    state::{CollectionItemChangeType, LockPolicy, MintConfig, MintWeightMode},
+};
 use program_test::tools::*;
 use solana_program_test::*;
 use solana_sdk::transport::TransportError;
@@ -200,3 +203,188 @@ async fn test_update_voter_weight_record_with_token_owner_record_must_match_erro
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_unlocked_tokens_moved_to_other_token_account_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+    let mint_cookie = realm_voter_test.bench.with_mint().await?;
+
+    let registrar_cookie = realm_voter_test
+        .with_registrar_using_mints(
+            &realm_cookie,
+            &[MintConfig {
+                mint: mint_cookie.address,
+                weight_mode: MintWeightMode::Fixed,
+                weight: 10,
+                digit_shift: 0,
+                lock_policy: LockPolicy::Unlocked,
+                min_hold_slots: 1,
+                min_balance: 0,
+            }],
+        )
+        .await?;
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let token_account_cookie = realm_voter_test
+        .bench
+        .with_tokens(&mint_cookie, &token_owner_cookie.address, 100)
+        .await?;
+
+    let token_hold_record_cookie = realm_voter_test
+        .update_token_hold_record(
+            &registrar_cookie,
+            &token_account_cookie.address,
+            &mint_cookie.address,
+            &token_owner_cookie,
+        )
+        .await?;
+
+    realm_voter_test.bench.advance_clock().await;
+
+    // Vote with the held tokens
+    realm_voter_test
+        .update_voter_weight_record_using_token_accounts(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[(
+                token_account_cookie.address,
+                Some(token_hold_record_cookie.address),
+            )],
+        )
+        .await?;
+
+    // Move the tokens to another token account of the same owner
+    let token_account_cookie2 = realm_voter_test
+        .bench
+        .with_tokens(&mint_cookie, &token_owner_cookie.address, 0)
+        .await?;
+
+    realm_voter_test
+        .bench
+        .transfer_tokens(
+            &token_account_cookie.address,
+            &token_account_cookie2.address,
+            &token_owner_cookie.signer,
+            100,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record_using_token_accounts(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[(
+                token_account_cookie2.address,
+                Some(token_hold_record_cookie.address),
+            )],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, TokenHaverError::InvalidTokenHoldRecord);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_token_hold_record_bound_to_other_token_account_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut realm_voter_test = RealmVoterTest::start_new().await;
+
+    let realm_cookie = realm_voter_test.governance.with_realm().await?;
+    let mint_cookie = realm_voter_test.bench.with_mint().await?;
+
+    let registrar_cookie = realm_voter_test
+        .with_registrar_using_mints(
+            &realm_cookie,
+            &[MintConfig {
+                mint: mint_cookie.address,
+                weight_mode: MintWeightMode::Fixed,
+                weight: 10,
+                digit_shift: 0,
+                lock_policy: LockPolicy::Unlocked,
+                min_hold_slots: 1,
+                min_balance: 0,
+            }],
+        )
+        .await?;
+
+    let token_owner_cookie = realm_voter_test.bench.with_wallet().await;
+
+    let mut voter_weight_record_cookie = realm_voter_test
+        .with_voter_weight_record(&registrar_cookie, &token_owner_cookie)
+        .await?;
+
+    let token_account_cookie = realm_voter_test
+        .bench
+        .with_tokens(&mint_cookie, &token_owner_cookie.address, 100)
+        .await?;
+
+    realm_voter_test
+        .update_token_hold_record(
+            &registrar_cookie,
+            &token_account_cookie.address,
+            &mint_cookie.address,
+            &token_owner_cookie,
+        )
+        .await?;
+
+    realm_voter_test.bench.advance_clock().await;
+
+    // Move the held tokens to another token account and bind the record to it
+    let token_account_cookie2 = realm_voter_test
+        .bench
+        .with_tokens(&mint_cookie, &token_owner_cookie.address, 0)
+        .await?;
+
+    realm_voter_test
+        .bench
+        .transfer_tokens(
+            &token_account_cookie.address,
+            &token_account_cookie2.address,
+            &token_owner_cookie.signer,
+            100,
+        )
+        .await?;
+
+    let token_hold_record_cookie = realm_voter_test
+        .update_token_hold_record(
+            &registrar_cookie,
+            &token_account_cookie2.address,
+            &mint_cookie.address,
+            &token_owner_cookie,
+        )
+        .await?;
+
+    // Act
+    let err = realm_voter_test
+        .update_voter_weight_record_using_token_accounts(
+            &registrar_cookie,
+            &mut voter_weight_record_cookie,
+            &[(
+                token_account_cookie2.address,
+                Some(token_hold_record_cookie.address),
+            )],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_realm_voter_err(err, TokenHaverError::MinHoldSlotsNotReached);
+
+    Ok(())
+}
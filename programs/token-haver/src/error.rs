@@ -23,9 +23,51 @@ pub enum TokenHaverError {
     #[msg("All token accounts' mints must be included in the registrar")]
     TokenAccountWrongMint,
 
-    #[msg("All token accounts must be locked")]
+    #[msg("All token accounts must be frozen or non-transferable")]
     TokenAccountNotLocked,
 
     #[msg("All token accounts' mints must be unique")]
     TokenAccountDuplicateMint,
+
+    #[msg("Math Overflow in VoterWeight")]
+    VoterWeightOverflow,
+
+    #[msg("Mint accounts must be provided for all configured mints")]
+    MintAccountsCountMismatch,
+
+    #[msg("Mint account doesn't match the mint config")]
+    InvalidMintAccount,
+
+    #[msg("Mint is not configured for the Registrar")]
+    MintNotConfigured,
+
+    #[msg("Input voter weight required when previous voter weight plugin is configured")]
+    MissingInputVoterWeight,
+
+    #[msg("Min hold slots must be set for unlocked mints")]
+    InvalidMinHoldSlots,
+
+    #[msg("Lock account must follow the token account")]
+    MissingLockAccount,
+
+    #[msg("Invalid lock program delegate")]
+    InvalidLockDelegate,
+
+    #[msg("Invalid TokenHoldRecord")]
+    InvalidTokenHoldRecord,
+
+    #[msg("Token account balance wasn't held for the min hold slots")]
+    MinHoldSlotsNotReached,
+
+    #[msg("Digit shift is out of the supported range")]
+    InvalidDigitShift,
+
+    #[msg("Registrar has already been migrated")]
+    RegistrarAlreadyMigrated,
+
+    #[msg("Token account balance decreased since TokenHoldRecord was updated")]
+    TokenHoldRecordOutdated,
+
+    #[msg("Token account owner must sign to bind TokenHoldRecord to the token account")]
+    GoverningTokenOwnerMustSign,
 }
@@ -0,0 +1,94 @@
+use crate::error::TokenHaverError;
+use crate::state::max_voter_weight_record::MaxVoterWeightRecord;
+use crate::state::*;
+use crate::tools::account::resize_account;
+use anchor_lang::prelude::*;
+use spl_governance::state::realm;
+
+/// Inserts, updates or removes a single mint config of the Registrar
+/// The Registrar is resized to fit the mints and the rent is refunded to lamports_destination when it shrinks
+/// When MaxVoterWeightRecord is provided its max voter weight is recomputed for the new mints
+/// and the mint accounts of all the mints must be provided as remaining accounts
+#[derive(Accounts)]
+#[instruction(mint_config: MintConfig, change_type: CollectionItemChangeType)]
+pub struct ConfigureMint<'info> {
+    /// The Registrar for the given realm and governing_token_mint
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        address = registrar.realm @ TokenHaverError::InvalidRealmForRegistrar,
+        owner = registrar.governance_program_id
+     )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign and match realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// Optional MaxVoterWeightRecord of the Registrar to recompute
+    #[account(
+        mut,
+        seeds = [ b"max-voter-weight-record".as_ref(),
+                registrar.realm.key().as_ref(),
+                registrar.governing_token_mint.key().as_ref()],
+        bump,
+    )]
+    pub max_voter_weight_record: Option<Account<'info, MaxVoterWeightRecord>>,
+
+    /// Funds the rent when the Registrar grows
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Receives the rent refund when the Registrar shrinks
+    /// CHECK: Any account can receive the refund
+    #[account(mut)]
+    pub lamports_destination: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_mint<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ConfigureMint<'info>>,
+    mint_config: MintConfig,
+    change_type: CollectionItemChangeType,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let realm = realm::get_realm_data_for_governing_token_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.realm,
+        &registrar.governing_token_mint,
+    )?;
+
+    require_eq!(
+        realm.authority.unwrap(),
+        ctx.accounts.realm_authority.key(),
+        TokenHaverError::InvalidRealmAuthority
+    );
+
+    match change_type {
+        CollectionItemChangeType::Upsert => registrar.upsert_mint(mint_config)?,
+        CollectionItemChangeType::Remove => registrar.remove_mint(&mint_config.mint)?,
+    }
+
+    let new_size = Registrar::get_space(
+        registrar.mint_configs.len() as u8,
+        registrar.lock_programs.len() as u8,
+    );
+
+    resize_account(
+        &registrar.to_account_info(),
+        new_size,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.lamports_destination.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    if let Some(max_voter_weight_record) = &mut ctx.accounts.max_voter_weight_record {
+        let mint_supplies = resolve_mint_supplies(registrar, ctx.remaining_accounts)?;
+        max_voter_weight_record.update_max_voter_weight(registrar, &mint_supplies)?;
+    }
+
+    Ok(())
+}
@@ -1,12 +1,15 @@
 use crate::error::TokenHaverError;
+use crate::state::max_voter_weight_record::MaxVoterWeightRecord;
 use crate::state::*;
-use anchor_lang::system_program::Transfer;
-use anchor_lang::{prelude::*, system_program};
+use crate::tools::account::resize_account;
+use anchor_lang::prelude::*;
 use spl_governance::state::realm;
 
-/// Configures mints for Registrar
+/// Configures mints and lock programs for Registrar
+/// When MaxVoterWeightRecord is provided its max voter weight is recomputed for the new mints
+/// and the mint accounts of all the mints must be provided as remaining accounts
 #[derive(Accounts)]
-#[instruction(mints: Vec<Pubkey>)]
+#[instruction(mints: Vec<MintConfig>, lock_programs: Vec<Pubkey>)]
 pub struct ConfigureMints<'info> {
     /// The Registrar for the given realm and governing_token_mint
     #[account(mut)]
@@ -19,43 +22,44 @@ pub struct ConfigureMints<'info> {
     /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
     pub realm: UncheckedAccount<'info>,
 
-    // will pay in the event of a resize
+    /// Funds the rent when the Registrar grows and receives the rent refund when it shrinks
+    #[account(mut)]
     pub payer: Signer<'info>,
 
     /// Authority of the Realm must sign and match realm.authority
     pub realm_authority: Signer<'info>,
 
+    /// Optional MaxVoterWeightRecord of the Registrar to recompute
+    #[account(
+        mut,
+        seeds = [ b"max-voter-weight-record".as_ref(),
+                registrar.realm.key().as_ref(),
+                registrar.governing_token_mint.key().as_ref()],
+        bump,
+    )]
+    pub max_voter_weight_record: Option<Account<'info, MaxVoterWeightRecord>>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn configure_mints(ctx: Context<ConfigureMints>, mints: Vec<Pubkey>) -> Result<()> {
-    let new_size = Registrar::get_space(mints.len() as u8);
-
-    let rent = Rent::get()?;
-    let new_minimum_balance = rent.minimum_balance(new_size);
-
-    let lamports_diff =
-        new_minimum_balance.saturating_sub(ctx.accounts.registrar.to_account_info().lamports());
-
-    // if lamports_diff is positive, we need to fund the account
-    if lamports_diff > 0 {
-        // Create a CPI context for the transfer
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.payer.to_account_info().clone(),
-            to: ctx.accounts.registrar.to_account_info().clone(),
-        };
-
-        let cpi_program = ctx.accounts.system_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-
-        // Perform the transfer
-        system_program::transfer(cpi_ctx, lamports_diff)?;
-    }
+pub fn configure_mints<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ConfigureMints<'info>>,
+    mints: Vec<MintConfig>,
+    lock_programs: Vec<Pubkey>,
+) -> Result<()> {
+    let new_size = Registrar::get_space(mints.len() as u8, lock_programs.len() as u8);
+
+    resize_account(
+        &ctx.accounts.registrar.to_account_info(),
+        new_size,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
 
     let registrar = &mut ctx.accounts.registrar;
-    registrar.to_account_info().realloc(new_size, false)?;
 
-    registrar.mints = mints;
+    registrar.set_mints(mints, lock_programs)?;
 
     let realm = realm::get_realm_data_for_governing_token_mint(
         &registrar.governance_program_id,
@@ -69,5 +73,10 @@ pub fn configure_mints(ctx: Context<ConfigureMints>, mints: Vec<Pubkey>) -> Resu
         TokenHaverError::InvalidRealmAuthority
     );
 
+    if let Some(max_voter_weight_record) = &mut ctx.accounts.max_voter_weight_record {
+        let mint_supplies = resolve_mint_supplies(registrar, ctx.remaining_accounts)?;
+        max_voter_weight_record.update_max_voter_weight(registrar, &mint_supplies)?;
+    }
+
     Ok(())
 }
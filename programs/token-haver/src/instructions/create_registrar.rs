@@ -5,9 +5,10 @@ use anchor_spl::token::Mint;
 use spl_governance::state::realm;
 
 /// Creates Registrar storing Realm Voter configuration for spl-governance Realm
+/// The mints are configured with their weights and weight modes
 /// This instruction should only be executed once per realm/governing_token_mint to create the account
 #[derive(Accounts)]
-#[instruction(mints: Vec<Pubkey>)]
+#[instruction(mints: Vec<MintConfig>, lock_programs: Vec<Pubkey>)]
 pub struct CreateRegistrar<'info> {
     /// The Realm Voter Registrar
     /// There can only be a single registrar per governance Realm and governing mint of the Realm
@@ -16,7 +17,7 @@ pub struct CreateRegistrar<'info> {
         seeds = [b"registrar".as_ref(),realm.key().as_ref(), governing_token_mint.key().as_ref()],
         bump,
         payer = payer,
-        space = Registrar::get_space(mints.len() as u8),
+        space = Registrar::get_space(mints.len() as u8, lock_programs.len() as u8),
     )]
     pub registrar: Account<'info, Registrar>,
 
@@ -25,6 +26,11 @@ pub struct CreateRegistrar<'info> {
     #[account(executable)]
     pub governance_program_id: UncheckedAccount<'info>,
 
+    /// The program id of the previous voter weight plugin when the plugin is used in a sequence
+    /// CHECK: Can be any voter weight plugin and it's not known at the compilation time
+    #[account(executable)]
+    pub previous_voter_weight_plugin_program_id: Option<UncheckedAccount<'info>>,
+
     /// An spl-governance Realm
     ///
     /// Realm is validated in the instruction:
@@ -57,15 +63,30 @@ pub struct CreateRegistrar<'info> {
 /// To use the registrar, call ConfigureGovernanceProgram to register spl-governance instance which will be
 /// used for governance
 ///
-pub fn create_registrar(ctx: Context<CreateRegistrar>, mints: Vec<Pubkey>) -> Result<()> {
+pub fn create_registrar(
+    ctx: Context<CreateRegistrar>,
+    mints: Vec<MintConfig>,
+    lock_programs: Vec<Pubkey>,
+) -> Result<()> {
     let registrar = &mut ctx.accounts.registrar;
     **registrar = Registrar {
         governance_program_id: ctx.accounts.governance_program_id.key(),
         realm: ctx.accounts.realm.key(),
         governing_token_mint: ctx.accounts.governing_token_mint.key(),
-        mints,
+        mints: vec![],
+        mint_configs: vec![],
+        lock_programs: vec![],
+        previous_voter_weight_plugin_program_id: ctx
+            .accounts
+            .previous_voter_weight_plugin_program_id
+            .as_ref()
+            .map(|previous_voter_weight_plugin_program_info| {
+                previous_voter_weight_plugin_program_info.key()
+            }),
     };
 
+    registrar.set_mints(mints, lock_programs)?;
+
     // Verify that realm_authority is the expected authority of the Realm
     // and that the mint matches one of the realm mints too
     let realm = realm::get_realm_data_for_governing_token_mint(
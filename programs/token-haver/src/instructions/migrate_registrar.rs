@@ -0,0 +1,59 @@
+use crate::error::TokenHaverError;
+use crate::state::*;
+use crate::tools::account::resize_account;
+use anchor_lang::{prelude::*, Discriminator};
+
+/// Migrates Registrar with the original account layout which only stored the mints
+/// The mints are converted to MintConfigs which grant the same fixed weight for frozen token accounts
+/// The instruction is permissionless because the migrated Registrar grants the same voter weights
+#[derive(Accounts)]
+pub struct MigrateRegistrar<'info> {
+    /// The Registrar with the original account layout
+    /// CHECK: Owned by the program and deserialized in the instruction
+    /// because it can't be deserialized as Registrar before the migration
+    #[account(mut, owner = crate::id())]
+    pub registrar: UncheckedAccount<'info>,
+
+    /// Funds the rent of the migrated Registrar
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_registrar(ctx: Context<MigrateRegistrar>) -> Result<()> {
+    let registrar_info = ctx.accounts.registrar.to_account_info();
+
+    let legacy_registrar = {
+        let data = registrar_info.try_borrow_data()?;
+
+        require!(
+            data.starts_with(&Registrar::DISCRIMINATOR),
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        LegacyRegistrar::deserialize(&mut &data[Registrar::DISCRIMINATOR.len()..])?
+    };
+
+    // Registrars with the original layout were always sized to fit their mints exactly
+    // and the migrated Registrars are larger than the original layout with the same number of mints
+    require_eq!(
+        registrar_info.data_len(),
+        LegacyRegistrar::get_space(legacy_registrar.mints.len()),
+        TokenHaverError::RegistrarAlreadyMigrated
+    );
+
+    let registrar = legacy_registrar.migrate();
+
+    resize_account(
+        &registrar_info,
+        Registrar::get_space(registrar.mint_configs.len() as u8, 0),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    registrar.try_serialize(&mut &mut registrar_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
@@ -9,3 +9,18 @@ mod update_voter_weight_record;
 
 pub use configure_mints::*;
 mod configure_mints;
+
+pub use configure_mint::*;
+mod configure_mint;
+
+pub use migrate_registrar::*;
+mod migrate_registrar;
+
+pub use create_max_voter_weight_record::*;
+mod create_max_voter_weight_record;
+
+pub use update_max_voter_weight_record::*;
+mod update_max_voter_weight_record;
+
+pub use update_token_hold_record::*;
+mod update_token_hold_record;
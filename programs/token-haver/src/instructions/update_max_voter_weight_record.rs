@@ -0,0 +1,35 @@
+use crate::state::max_voter_weight_record::MaxVoterWeightRecord;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Updates MaxVoterWeightRecord based on the mints configured in the Registrar
+/// The mint accounts of all MintConfigs must be provided
+/// as remaining accounts in the order of Registrar.mint_configs to resolve their current supplies
+///
+/// This instruction is permissionless and can be executed by anyone to refresh the max voter weight
+#[derive(Accounts)]
+pub struct UpdateMaxVoterWeightRecord<'info> {
+    /// The Registrar the MaxVoterWeightRecord account belongs to
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        seeds = [ b"max-voter-weight-record".as_ref(),
+                registrar.realm.key().as_ref(),
+                registrar.governing_token_mint.key().as_ref()],
+        bump,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+pub fn update_max_voter_weight_record<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateMaxVoterWeightRecord<'info>>,
+) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+
+    let mint_supplies = resolve_mint_supplies(registrar, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .max_voter_weight_record
+        .update_max_voter_weight(registrar, &mint_supplies)
+}
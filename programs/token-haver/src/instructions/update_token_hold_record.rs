@@ -0,0 +1,77 @@
+use crate::error::TokenHaverError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+/// Creates or updates TokenHoldRecord of the token account owner and mint for the given token account
+/// The record tracks for how long the balance has been held and it's required to count token accounts
+/// of mints with the Unlocked lock policy
+///
+/// Binding the record to a token account, including its creation, must be signed by the token account owner
+/// Updating the balance of the bound token account is permissionless and should be executed whenever the balance changes
+#[derive(Accounts)]
+pub struct UpdateTokenHoldRecord<'info> {
+    /// The Registrar the TokenHoldRecord belongs to
+    pub registrar: Account<'info, Registrar>,
+
+    /// The token account to track
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The owner of the token account
+    /// It's only required when the record is bound to the token account
+    pub governing_token_owner: Option<Signer<'info>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [ b"token-hold-record".as_ref(),
+                registrar.key().as_ref(),
+                token_account.owner.as_ref(),
+                token_account.mint.as_ref()],
+        bump,
+        payer = payer,
+        space = TokenHoldRecord::get_space()
+    )]
+    pub token_hold_record: Account<'info, TokenHoldRecord>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_token_hold_record(ctx: Context<UpdateTokenHoldRecord>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let token_account = &ctx.accounts.token_account;
+    let token_hold_record = &mut ctx.accounts.token_hold_record;
+
+    let mint_config = registrar.get_mint_config(&token_account.mint)?;
+
+    require!(
+        mint_config.lock_policy == LockPolicy::Unlocked,
+        TokenHaverError::InvalidTokenHoldRecord
+    );
+
+    // Binding the record to another token account restarts the hold period and only the owner can do it
+    if token_hold_record.token_account != token_account.key() {
+        require!(
+            ctx.accounts
+                .governing_token_owner
+                .as_ref()
+                .is_some_and(
+                    |governing_token_owner| governing_token_owner.key() == token_account.owner
+                ),
+            TokenHaverError::GoverningTokenOwnerMustSign
+        );
+    }
+
+    token_hold_record.registrar = registrar.key();
+    token_hold_record.governing_token_owner = token_account.owner;
+    token_hold_record.mint = token_account.mint;
+    token_hold_record.record_balance(
+        &token_account.key(),
+        token_account.amount,
+        Clock::get()?.slot,
+    );
+
+    Ok(())
+}
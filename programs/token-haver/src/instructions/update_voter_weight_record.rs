@@ -1,10 +1,24 @@
 use crate::error::TokenHaverError;
 use crate::state::*;
 use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::TokenAccount;
+use gpl_shared::compose::resolve_input_voter_weight;
+use gpl_shared::generic_voter_weight::GenericVoterWeight;
+use num_traits::FromPrimitive;
+use std::cmp::min;
+
+use crate::tools::token::is_token_account_non_transferable;
 
 /// Updates VoterWeightRecord based on Realm DAO membership
 /// The membership is evaluated via a valid TokenOwnerRecord which must belong to one of the configured spl-governance instances
+/// The weight of each locked token account is resolved using the MintConfig of its mint
+/// Token accounts can belong to either spl-token or Token-2022 and must be locked according to the LockPolicy of their mint
+///
+/// The token accounts are provided as remaining accounts
+/// and for LockProgram and Unlocked lock policies each token account must be followed by its lock account
+///
+/// When the Registrar has a predecessor plugin the weight of the token accounts is added on top of the predecessor weight
+/// and the action and target of the predecessor VoterWeightRecord are propagated
 ///
 /// This instruction sets VoterWeightRecord.voter_weight which is valid for the current slot only
 /// and must be executed inside the same transaction as the corresponding spl-gov instruction
@@ -22,6 +36,12 @@ pub struct UpdateVoterWeightRecord<'info> {
         @ TokenHaverError::InvalidVoterWeightRecordMint,
     )]
     pub voter_weight_record: Account<'info, VoterWeightRecord>,
+
+    /// An account that is either of type TokenOwnerRecordV2 or VoterWeightRecord
+    /// depending on whether the registrar includes a predecessor or not
+    /// It can be omitted when the registrar has no predecessor and then only the token accounts weight is granted
+    /// CHECK: Checked in the code depending on the registrar
+    pub input_voter_weight: Option<UncheckedAccount<'info>>,
 }
 
 pub fn update_voter_weight_record<'info>(
@@ -29,15 +49,26 @@ pub fn update_voter_weight_record<'info>(
 ) -> Result<()> {
     let registrar = &ctx.accounts.registrar;
     let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+    let current_slot = Clock::get()?.slot;
+
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+    let mut token_account_mints: Vec<Pubkey> = vec![];
+    let mut voter_weight = 0u64;
+
+    while let Some(token_account_info) = remaining_accounts.next() {
+        let account = InterfaceAccount::<TokenAccount>::try_from(token_account_info)?;
 
-    let nonzero_token_accounts: Vec<Account<TokenAccount>> = ctx
-        .remaining_accounts
-        .iter()
-        .map(|account| Account::<TokenAccount>::try_from(account).unwrap())
-        .filter(|account| account.amount > 0) // filter out zero balance accounts
-        .collect();
+        // Skip zero balance accounts before any other checks and only consume their lock account
+        if account.amount == 0 {
+            if registrar
+                .get_mint_config(&account.mint)
+                .is_ok_and(|mint_config| mint_config.lock_policy.has_lock_account())
+            {
+                remaining_accounts.next();
+            }
+            continue;
+        }
 
-    for account in nonzero_token_accounts.iter() {
         // Throw an error if a token account's owner doesnt match token_owner_record.governing_token_owner
         require_eq!(
             account.owner,
@@ -47,27 +78,123 @@ pub fn update_voter_weight_record<'info>(
 
         // Throw an error if a token account's mint is not unique amount the accounts
         require!(
-            nonzero_token_accounts
-                .iter()
-                .filter(|a| a.mint == account.mint)
-                .count()
-                == 1,
+            !token_account_mints.contains(&account.mint),
             TokenHaverError::TokenAccountDuplicateMint
         );
+        token_account_mints.push(account.mint);
 
-        // Throw an error if a token account's mint isn't in registrar.mints
-        require!(
-            registrar.mints.contains(&account.mint),
-            TokenHaverError::TokenAccountWrongMint
-        );
-        // Throw an error if a token account is not frozen
-        require!(account.is_frozen(), TokenHaverError::TokenAccountNotLocked);
+        // Throw an error if a token account's mint isn't in registrar.mint_configs
+        let mint_config = registrar.get_mint_config(&account.mint)?;
+
+        // Throw an error if a token account isn't locked according to the mint lock policy
+        let locked_amount = match mint_config.lock_policy {
+            LockPolicy::Frozen => {
+                require!(account.is_frozen(), TokenHaverError::TokenAccountNotLocked);
+                account.amount
+            }
+            LockPolicy::NonTransferable => {
+                require!(
+                    is_token_account_non_transferable(&token_account_info.try_borrow_data()?)?,
+                    TokenHaverError::TokenAccountNotLocked
+                );
+                account.amount
+            }
+            LockPolicy::LockProgram => {
+                let delegate_info = remaining_accounts
+                    .next()
+                    .ok_or_else(|| error!(TokenHaverError::MissingLockAccount))?;
+
+                require!(account.is_frozen(), TokenHaverError::TokenAccountNotLocked);
+
+                require!(
+                    account.delegate == Some(delegate_info.key()).into()
+                        && registrar.lock_programs.contains(delegate_info.owner),
+                    TokenHaverError::InvalidLockDelegate
+                );
+
+                account.amount.min(account.delegated_amount)
+            }
+            LockPolicy::Unlocked => {
+                let token_hold_record_info = remaining_accounts
+                    .next()
+                    .ok_or_else(|| error!(TokenHaverError::MissingLockAccount))?;
+
+                let token_hold_record =
+                    Account::<TokenHoldRecord>::try_from(token_hold_record_info)?;
+
+                // The record must be the one of the voter and mint and bound to the token account
+                require!(
+                    token_hold_record.registrar == registrar.key()
+                        && token_hold_record.governing_token_owner
+                            == voter_weight_record.governing_token_owner
+                        && token_hold_record.mint == account.mint
+                        && token_hold_record.token_account == token_account_info.key(),
+                    TokenHaverError::InvalidTokenHoldRecord
+                );
+
+                token_hold_record.get_held_amount(
+                    account.amount,
+                    current_slot,
+                    mint_config.min_hold_slots,
+                )?
+            }
+        };
+
+        // Accounts with locked balance below the mint min balance don't contribute any weight
+        if mint_config.is_countable_balance(locked_amount) {
+            voter_weight = voter_weight
+                .checked_add(mint_config.get_weight(locked_amount)?)
+                .ok_or_else(|| error!(TokenHaverError::VoterWeightOverflow))?;
+        }
     }
 
-    // Setup voter_weight
-    voter_weight_record.voter_weight = (nonzero_token_accounts.len() as u64) * 1_000_000;
+    match &ctx.accounts.input_voter_weight {
+        Some(input_voter_weight) => {
+            let input_voter_weight_account = input_voter_weight.to_account_info();
+
+            let clone_record = voter_weight_record.clone();
+            let input_voter_weight_record =
+                resolve_input_voter_weight(&input_voter_weight_account, &clone_record, registrar)?;
+
+            // Setup voter_weight on top of the input voter weight
+            voter_weight_record.voter_weight = input_voter_weight_record
+                .get_voter_weight()
+                .checked_add(voter_weight)
+                .ok_or_else(|| error!(TokenHaverError::VoterWeightOverflow))?;
+
+            // Token accounts weight is only valid as of the current slot
+            // and the input voter weight can't be extended beyond its own expiry
+            voter_weight_record.voter_weight_expiry =
+                input_voter_weight_record.get_voter_weight_expiry().map_or(
+                    Some(current_slot), // no previous expiry, use current slot
+                    |previous_expiry| Some(min(previous_expiry, current_slot)),
+                );
+
+            // Propagate action and target of the input voter weight
+            voter_weight_record.weight_action = input_voter_weight_record
+                .get_weight_action()
+                .map(|x| FromPrimitive::from_u32(x as u32).unwrap());
+            voter_weight_record.weight_action_target =
+                input_voter_weight_record.get_weight_action_target();
+        }
+        None => {
+            // The predecessor weight can't be skipped when the plugin is configured in a sequence
+            require!(
+                registrar.previous_voter_weight_plugin_program_id.is_none(),
+                TokenHaverError::MissingInputVoterWeight
+            );
+
+            // Setup voter_weight
+            voter_weight_record.voter_weight = voter_weight;
+
+            // Record is only valid as of the current slot
+            voter_weight_record.voter_weight_expiry = Some(current_slot);
+
+            // Set action and target to None to indicate the weight is valid for any action and target
+            voter_weight_record.weight_action = None;
+            voter_weight_record.weight_action_target = None;
+        }
+    }
 
-    // Record is only valid as of the current slot
-    voter_weight_record.voter_weight_expiry = Some(Clock::get()?.slot);
     Ok(())
 }
@@ -4,6 +4,7 @@ pub mod error;
 
 mod instructions;
 use instructions::*;
+use state::{CollectionItemChangeType, MintConfig};
 
 pub mod state;
 
@@ -16,9 +17,13 @@ pub mod token_haver {
 
     use super::*;
 
-    pub fn create_registrar(ctx: Context<CreateRegistrar>, mints: Vec<Pubkey>) -> Result<()> {
+    pub fn create_registrar(
+        ctx: Context<CreateRegistrar>,
+        mints: Vec<MintConfig>,
+        lock_programs: Vec<Pubkey>,
+    ) -> Result<()> {
         log_version();
-        instructions::create_registrar(ctx, mints)
+        instructions::create_registrar(ctx, mints, lock_programs)
     }
     pub fn create_voter_weight_record(
         ctx: Context<CreateVoterWeightRecord>,
@@ -33,9 +38,39 @@ pub mod token_haver {
         log_version();
         instructions::update_voter_weight_record(ctx)
     }
-    pub fn configure_mints(ctx: Context<ConfigureMints>, mints: Vec<Pubkey>) -> Result<()> {
+    pub fn configure_mints<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ConfigureMints<'info>>,
+        mints: Vec<MintConfig>,
+        lock_programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_mints(ctx, mints, lock_programs)
+    }
+    pub fn configure_mint<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ConfigureMint<'info>>,
+        mint_config: MintConfig,
+        change_type: CollectionItemChangeType,
+    ) -> Result<()> {
+        log_version();
+        instructions::configure_mint(ctx, mint_config, change_type)
+    }
+    pub fn migrate_registrar(ctx: Context<MigrateRegistrar>) -> Result<()> {
+        log_version();
+        instructions::migrate_registrar(ctx)
+    }
+    pub fn create_max_voter_weight_record(ctx: Context<CreateMaxVoterWeightRecord>) -> Result<()> {
+        log_version();
+        instructions::create_max_voter_weight_record(ctx)
+    }
+    pub fn update_max_voter_weight_record<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateMaxVoterWeightRecord<'info>>,
+    ) -> Result<()> {
+        log_version();
+        instructions::update_max_voter_weight_record(ctx)
+    }
+    pub fn update_token_hold_record(ctx: Context<UpdateTokenHoldRecord>) -> Result<()> {
         log_version();
-        instructions::configure_mints(ctx, mints)
+        instructions::update_token_hold_record(ctx)
     }
 }
 
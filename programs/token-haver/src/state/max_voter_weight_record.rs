@@ -1,4 +1,5 @@
 use crate::id;
+use crate::state::Registrar;
 use crate::tools::anchor::DISCRIMINATOR_SIZE;
 use anchor_lang::prelude::Pubkey;
 use anchor_lang::prelude::*;
@@ -50,6 +51,25 @@ impl MaxVoterWeightRecord {
     pub fn get_space() -> usize {
         DISCRIMINATOR_SIZE + PUBKEY_BYTES * 2 + 8 + 1 + 8 + 8
     }
+
+    /// Sets max_voter_weight from the Registrar mints and their supplies
+    pub fn update_max_voter_weight(
+        &mut self,
+        registrar: &Registrar,
+        mint_supplies: &[u64],
+    ) -> Result<()> {
+        self.max_voter_weight = registrar.get_max_voter_weight(mint_supplies)?;
+
+        // The max voter weight changes with the mint supplies
+        // and hence it's only valid as of the current slot
+        self.max_voter_weight_expiry = if registrar.mint_configs.is_empty() {
+            None
+        } else {
+            Some(Clock::get()?.slot)
+        };
+
+        Ok(())
+    }
 }
 
 /// Returns MaxVoterWeightRecord PDA seeds
@@ -0,0 +1,302 @@
+use crate::error::TokenHaverError;
+use anchor_lang::prelude::*;
+use solana_program::pubkey::PUBKEY_BYTES;
+use std::convert::TryFrom;
+
+/// Defines how the voter weight is resolved from a token account of the configured mint
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum MintWeightMode {
+    /// The fixed MintConfig.weight is granted for a nonzero balance regardless of the amount
+    Fixed,
+
+    /// The weight is proportional to the balance
+    /// The amount is shifted by MintConfig.digit_shift and multiplied by MintConfig.weight
+    Proportional,
+}
+
+/// Defines how token accounts of the configured mint must be locked to be counted
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum LockPolicy {
+    /// The token account must be frozen
+    Frozen,
+
+    /// The token account must belong to a Token-2022 mint with the NonTransferable extension
+    NonTransferable,
+
+    /// The token account must be frozen and its balance delegated to an account owned by one of Registrar.lock_programs
+    /// The delegate account must follow the token account in the remaining accounts
+    LockProgram,
+
+    /// The token account doesn't have to be locked but its balance must be held for at least MintConfig.min_hold_slots
+    /// The TokenHoldRecord of the owner and mint bound to the token account must follow the token account in the remaining accounts
+    Unlocked,
+}
+
+impl LockPolicy {
+    /// Returns true if token accounts must be followed by their lock account in the remaining accounts
+    pub fn has_lock_account(&self) -> bool {
+        matches!(self, LockPolicy::LockProgram | LockPolicy::Unlocked)
+    }
+}
+
+/// Configuration of a mint which grants governance power
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct MintConfig {
+    /// The mint of the token accounts
+    pub mint: Pubkey,
+
+    /// How the weight is resolved from the token account balance
+    pub weight_mode: MintWeightMode,
+
+    /// The weight granted for a nonzero balance in Fixed mode
+    /// or the multiplier applied to the shifted balance in Proportional mode
+    pub weight: u64,
+
+    /// Number of digits to shift the balance in Proportional mode, applying a 10^digit_shift factor
+    pub digit_shift: i8,
+
+    /// How token accounts of the mint must be locked to be counted
+    pub lock_policy: LockPolicy,
+
+    /// The minimum number of slots the balance must be held for when the lock policy is Unlocked
+    pub min_hold_slots: u64,
+
+    /// The minimum locked balance a token account must hold to contribute weight
+    /// Token accounts with lower balances are ignored
+    pub min_balance: u64,
+}
+
+impl MintConfig {
+    pub const SPACE: usize = PUBKEY_BYTES + 1 + 8 + 1 + 1 + 8 + 8;
+
+    /// The max absolute digit shift
+    /// u64::MAX has 20 digits and any larger shift either zeroes or overflows every nonzero balance
+    pub const MAX_DIGIT_SHIFT: i8 = 19;
+
+    /// Asserts the MintConfig can be stored in the Registrar
+    pub fn assert_is_valid(&self) -> Result<()> {
+        require!(
+            (-Self::MAX_DIGIT_SHIFT..=Self::MAX_DIGIT_SHIFT).contains(&self.digit_shift),
+            TokenHaverError::InvalidDigitShift
+        );
+
+        // Balances of unlocked token accounts can be moved freely and the hold period is mandatory for them
+        if self.lock_policy == LockPolicy::Unlocked {
+            require_gt!(self.min_hold_slots, 0, TokenHaverError::InvalidMinHoldSlots);
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if the locked balance is nonzero and at least min_balance
+    pub fn is_countable_balance(&self, amount: u64) -> bool {
+        amount > 0 && amount >= self.min_balance
+    }
+
+    /// Returns the voter weight for the given nonzero token account balance
+    pub fn get_weight(&self, amount: u64) -> Result<u64> {
+        match self.weight_mode {
+            MintWeightMode::Fixed => Ok(self.weight),
+            MintWeightMode::Proportional => {
+                let compute = || -> Option<u64> {
+                    let amount = if self.digit_shift < 0 {
+                        (amount as u128).checked_div(
+                            10u128.checked_pow(self.digit_shift.checked_neg()? as u32)?,
+                        )?
+                    } else {
+                        (amount as u128)
+                            .checked_mul(10u128.checked_pow(self.digit_shift as u32)?)?
+                    };
+                    u64::try_from(amount.checked_mul(self.weight as u128)?).ok()
+                };
+                compute().ok_or_else(|| error!(TokenHaverError::VoterWeightOverflow))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    fn mint_config(weight_mode: MintWeightMode, weight: u64, digit_shift: i8) -> MintConfig {
+        MintConfig {
+            mint: Pubkey::default(),
+            weight_mode,
+            weight,
+            digit_shift,
+            lock_policy: LockPolicy::Frozen,
+            min_hold_slots: 0,
+            min_balance: 0,
+        }
+    }
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = MintConfig::SPACE;
+
+        // Act
+        let actual_space = mint_config(MintWeightMode::Fixed, 1, 0)
+            .try_to_vec()
+            .unwrap()
+            .len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+
+    #[test]
+    fn test_get_weight_with_fixed_mode() {
+        // Arrange
+        let mint_config = mint_config(MintWeightMode::Fixed, 1_000_000, 2);
+
+        // Act
+        let weight = mint_config.get_weight(42).unwrap();
+
+        // Assert
+        assert_eq!(weight, 1_000_000);
+    }
+
+    #[test]
+    fn test_get_weight_with_proportional_mode() {
+        // Arrange
+        let mint_config = mint_config(MintWeightMode::Proportional, 3, 2);
+
+        // Act
+        let weight = mint_config.get_weight(42).unwrap();
+
+        // Assert
+        assert_eq!(weight, 12_600);
+    }
+
+    #[test]
+    fn test_get_weight_with_proportional_mode_and_negative_digit_shift() {
+        // Arrange
+        let mint_config = mint_config(MintWeightMode::Proportional, 2, -3);
+
+        // Act
+        let weight = mint_config.get_weight(12_345).unwrap();
+
+        // Assert
+        assert_eq!(weight, 24);
+    }
+
+    #[test]
+    fn test_get_weight_with_proportional_mode_overflow_error() {
+        // Arrange
+        let mint_config = mint_config(MintWeightMode::Proportional, 2, 0);
+
+        // Act
+        let err = mint_config.get_weight(u64::MAX).err().unwrap();
+
+        // Assert
+        assert_eq!(err, error!(TokenHaverError::VoterWeightOverflow));
+    }
+
+    #[test]
+    fn test_get_weight_with_proportional_mode_and_min_digit_shift_error() {
+        // Arrange
+        let mint_config = mint_config(MintWeightMode::Proportional, 2, i8::MIN);
+
+        // Act
+        let err = mint_config.get_weight(12_345).err().unwrap();
+
+        // Assert
+        assert_eq!(err, error!(TokenHaverError::VoterWeightOverflow));
+    }
+
+    #[test]
+    fn test_assert_is_valid_with_max_digit_shift() {
+        // Arrange
+        let positive_mint_config =
+            mint_config(MintWeightMode::Proportional, 1, MintConfig::MAX_DIGIT_SHIFT);
+        let negative_mint_config = mint_config(
+            MintWeightMode::Proportional,
+            1,
+            -MintConfig::MAX_DIGIT_SHIFT,
+        );
+
+        // Act + Assert
+        assert!(positive_mint_config.assert_is_valid().is_ok());
+        assert!(negative_mint_config.assert_is_valid().is_ok());
+    }
+
+    #[test]
+    fn test_assert_is_valid_with_digit_shift_out_of_range_error() {
+        // Arrange
+        let positive_mint_config = mint_config(
+            MintWeightMode::Proportional,
+            1,
+            MintConfig::MAX_DIGIT_SHIFT + 1,
+        );
+        let negative_mint_config = mint_config(MintWeightMode::Proportional, 1, -39);
+
+        // Act
+        let positive_err = positive_mint_config.assert_is_valid().err().unwrap();
+        let negative_err = negative_mint_config.assert_is_valid().err().unwrap();
+
+        // Assert
+        assert_eq!(positive_err, error!(TokenHaverError::InvalidDigitShift));
+        assert_eq!(negative_err, error!(TokenHaverError::InvalidDigitShift));
+    }
+
+    #[test]
+    fn test_assert_is_valid_with_unlocked_policy_and_min_hold_slots() {
+        // Arrange
+        let mut mint_config = mint_config(MintWeightMode::Fixed, 1, 0);
+        mint_config.lock_policy = LockPolicy::Unlocked;
+        mint_config.min_hold_slots = 100;
+
+        // Act
+        let result = mint_config.assert_is_valid();
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_assert_is_valid_with_unlocked_policy_and_zero_min_hold_slots_error() {
+        // Arrange
+        let mut mint_config = mint_config(MintWeightMode::Fixed, 1, 0);
+        mint_config.lock_policy = LockPolicy::Unlocked;
+
+        // Act
+        let err = mint_config.assert_is_valid().err().unwrap();
+
+        // Assert
+        assert_eq!(err, error!(TokenHaverError::InvalidMinHoldSlots));
+    }
+
+    #[test]
+    fn test_has_lock_account() {
+        // Act + Assert
+        assert!(!LockPolicy::Frozen.has_lock_account());
+        assert!(!LockPolicy::NonTransferable.has_lock_account());
+        assert!(LockPolicy::LockProgram.has_lock_account());
+        assert!(LockPolicy::Unlocked.has_lock_account());
+    }
+
+    #[test]
+    fn test_is_countable_balance_with_zero_min_balance() {
+        // Arrange
+        let mint_config = mint_config(MintWeightMode::Fixed, 1, 0);
+
+        // Act + Assert
+        assert!(!mint_config.is_countable_balance(0));
+        assert!(mint_config.is_countable_balance(1));
+    }
+
+    #[test]
+    fn test_is_countable_balance_with_min_balance() {
+        // Arrange
+        let mut mint_config = mint_config(MintWeightMode::Fixed, 1, 0);
+        mint_config.min_balance = 100;
+
+        // Act + Assert
+        assert!(!mint_config.is_countable_balance(99));
+        assert!(mint_config.is_countable_balance(100));
+        assert!(mint_config.is_countable_balance(101));
+    }
+}
@@ -1,3 +1,6 @@
+pub use mint_config::*;
+pub mod mint_config;
+
 pub use registrar::*;
 pub mod registrar;
 
@@ -5,3 +8,6 @@ pub mod max_voter_weight_record;
 
 pub use voter_weight_record::*;
 pub mod voter_weight_record;
+
+pub use token_hold_record::*;
+pub mod token_hold_record;
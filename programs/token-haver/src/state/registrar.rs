@@ -1,10 +1,16 @@
-use crate::{id, tools::anchor::DISCRIMINATOR_SIZE};
+use crate::{
+    error::TokenHaverError,
+    id,
+    state::{LockPolicy, MintConfig, MintWeightMode},
+    tools::anchor::DISCRIMINATOR_SIZE,
+};
 use anchor_lang::prelude::*;
-use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use anchor_spl::token_interface::Mint;
+use gpl_shared::compose::RegistrarBase;
 use solana_program::pubkey::PUBKEY_BYTES;
 
 /// Enum defining collection item change type
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
 pub enum CollectionItemChangeType {
     /// Update item in the collection if it already exists and Insert the item if it doesn't
     Upsert,
@@ -28,15 +34,211 @@ pub struct Registrar {
     /// and the actual token of the mint is not used
     pub governing_token_mint: Pubkey,
 
+    /// Mints of the original account layout which granted a fixed weight for frozen token accounts
+    /// They are converted to mint_configs by MigrateRegistrar and it's always empty for migrated Registrars
     pub mints: Vec<Pubkey>,
+
+    /// Mints granting governance power and their weights
+    pub mint_configs: Vec<MintConfig>,
+
+    /// Programs which can lock token accounts of the mints with LockProgram lock policy
+    pub lock_programs: Vec<Pubkey>,
+
+    /// If the plugin is one in a sequence, this is the previous plugin program ID
+    /// If set, then update_voter_weight_record will expect a voter_weight_record owned by this program
+    /// and the weight of the token accounts is added on top of the predecessor weight
+    pub previous_voter_weight_plugin_program_id: Option<Pubkey>,
 }
 
 impl Registrar {
-    pub fn get_space(max_mints: u8) -> usize {
-        DISCRIMINATOR_SIZE + PUBKEY_BYTES * 3 + 4 + max_mints as usize * PUBKEY_BYTES
+    /// The weight granted for each frozen token account by Registrars with the original account layout
+    pub const LEGACY_MINT_WEIGHT: u64 = 1_000_000;
+
+    pub fn get_space(max_mints: u8, max_lock_programs: u8) -> usize {
+        DISCRIMINATOR_SIZE
+            + PUBKEY_BYTES * 3
+            + 4
+            + 4
+            + max_mints as usize * MintConfig::SPACE
+            + 4
+            + max_lock_programs as usize * PUBKEY_BYTES
+            + 1
+            + PUBKEY_BYTES
+    }
+
+    /// Sets the mints and lock programs of the Registrar
+    pub fn set_mints(&mut self, mints: Vec<MintConfig>, lock_programs: Vec<Pubkey>) -> Result<()> {
+        for mint_config in mints.iter() {
+            mint_config.assert_is_valid()?;
+        }
+
+        self.mint_configs = mints;
+        self.lock_programs = lock_programs;
+
+        Ok(())
+    }
+
+    /// Updates the MintConfig if the mint is already configured and inserts it if it's not
+    pub fn upsert_mint(&mut self, mint_config: MintConfig) -> Result<()> {
+        mint_config.assert_is_valid()?;
+
+        match self
+            .mint_configs
+            .iter()
+            .position(|mc| mc.mint == mint_config.mint)
+        {
+            Some(mint_idx) => self.mint_configs[mint_idx] = mint_config,
+            None => self.mint_configs.push(mint_config),
+        }
+
+        Ok(())
+    }
+
+    /// Removes the MintConfig of the given mint
+    pub fn remove_mint(&mut self, mint: &Pubkey) -> Result<()> {
+        let mint_idx = self
+            .mint_configs
+            .iter()
+            .position(|mc| mc.mint == *mint)
+            .ok_or_else(|| error!(TokenHaverError::MintNotConfigured))?;
+
+        self.mint_configs.remove(mint_idx);
+
+        Ok(())
+    }
+
+    /// Returns the MintConfig for the given mint
+    pub fn get_mint_config(&self, mint: &Pubkey) -> Result<&MintConfig> {
+        self.mint_configs
+            .iter()
+            .find(|mint_config| mint_config.mint == *mint)
+            .ok_or_else(|| error!(TokenHaverError::TokenAccountWrongMint))
+    }
+
+    /// Returns the max voter weight which can be granted by the configured mints
+    /// Fixed mints contribute their weight for every token of the supply, which bounds the number of holders,
+    /// and Proportional mints the weight of their whole supply
+    /// mint_supplies must hold the supplies of all the mints in the order of the mints
+    pub fn get_max_voter_weight(&self, mint_supplies: &[u64]) -> Result<u64> {
+        let mut mint_supplies = mint_supplies.iter();
+        let mut max_voter_weight = 0u64;
+
+        for mint_config in self.mint_configs.iter() {
+            let mint_supply = mint_supplies
+                .next()
+                .ok_or_else(|| error!(TokenHaverError::MintAccountsCountMismatch))?;
+
+            let mint_weight = match mint_config.weight_mode {
+                MintWeightMode::Fixed => mint_config
+                    .weight
+                    .checked_mul(*mint_supply)
+                    .ok_or_else(|| error!(TokenHaverError::VoterWeightOverflow))?,
+                MintWeightMode::Proportional => mint_config.get_weight(*mint_supply)?,
+            };
+
+            max_voter_weight = max_voter_weight
+                .checked_add(mint_weight)
+                .ok_or_else(|| error!(TokenHaverError::VoterWeightOverflow))?;
+        }
+
+        require!(
+            mint_supplies.next().is_none(),
+            TokenHaverError::MintAccountsCountMismatch
+        );
+
+        Ok(max_voter_weight)
+    }
+}
+
+/// Registrar with the original account layout which only stored the mints
+/// It shares the Registrar discriminator and it's only used to migrate the Registrar with MigrateRegistrar
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq)]
+pub struct LegacyRegistrar {
+    pub governance_program_id: Pubkey,
+    pub realm: Pubkey,
+    pub governing_token_mint: Pubkey,
+    pub mints: Vec<Pubkey>,
+}
+
+impl LegacyRegistrar {
+    pub fn get_space(mints: usize) -> usize {
+        DISCRIMINATOR_SIZE + PUBKEY_BYTES * 3 + 4 + mints * PUBKEY_BYTES
+    }
+
+    /// Returns Registrar with the mints converted to MintConfigs granting the same fixed weight for frozen token accounts
+    pub fn migrate(self) -> Registrar {
+        let mint_configs = self
+            .mints
+            .iter()
+            .map(|mint| MintConfig {
+                mint: *mint,
+                weight_mode: MintWeightMode::Fixed,
+                weight: Registrar::LEGACY_MINT_WEIGHT,
+                digit_shift: 0,
+                lock_policy: LockPolicy::Frozen,
+                min_hold_slots: 0,
+                min_balance: 0,
+            })
+            .collect();
+
+        Registrar {
+            governance_program_id: self.governance_program_id,
+            realm: self.realm,
+            governing_token_mint: self.governing_token_mint,
+            mints: vec![],
+            mint_configs,
+            lock_programs: vec![],
+            previous_voter_weight_plugin_program_id: None,
+        }
+    }
+}
+
+impl<'a> RegistrarBase<'a> for Registrar {
+    fn get_realm(&'a self) -> &'a Pubkey {
+        &self.realm
+    }
+
+    fn get_governance_program_id(&'a self) -> &'a Pubkey {
+        &self.governance_program_id
+    }
+
+    fn get_governing_token_mint(&'a self) -> &'a Pubkey {
+        &self.governing_token_mint
+    }
+
+    fn get_previous_voter_weight_plugin_program_id(&'a self) -> &'a Option<Pubkey> {
+        &self.previous_voter_weight_plugin_program_id
     }
 }
 
+/// Resolves the supplies of the mints of the Registrar
+/// The mint accounts must be provided in the order of Registrar.mint_configs
+pub fn resolve_mint_supplies<'info>(
+    registrar: &Registrar,
+    mint_accounts: &'info [AccountInfo<'info>],
+) -> Result<Vec<u64>> {
+    require_eq!(
+        registrar.mint_configs.len(),
+        mint_accounts.len(),
+        TokenHaverError::MintAccountsCountMismatch
+    );
+
+    registrar
+        .mint_configs
+        .iter()
+        .zip(mint_accounts.iter())
+        .map(|(mint_config, mint_info)| {
+            require_keys_eq!(
+                mint_config.mint,
+                mint_info.key(),
+                TokenHaverError::InvalidMintAccount
+            );
+
+            Ok(InterfaceAccount::<Mint>::try_from(mint_info)?.supply)
+        })
+        .collect()
+}
+
 /// Returns Registrar PDA seeds
 pub fn get_registrar_seeds<'a>(
     realm: &'a Pubkey,
@@ -58,13 +260,27 @@ mod test {
     #[test]
     fn test_get_space() {
         // Arrange
-        let expected_space = Registrar::get_space(3);
+        let expected_space = Registrar::get_space(3, 2);
 
         let registrar = Registrar {
             governance_program_id: Pubkey::default(),
             realm: Pubkey::default(),
             governing_token_mint: Pubkey::default(),
-            mints: vec![Pubkey::default(), Pubkey::default(), Pubkey::default()],
+            mints: vec![],
+            mint_configs: vec![
+                MintConfig {
+                    mint: Pubkey::default(),
+                    weight_mode: MintWeightMode::Fixed,
+                    weight: 1,
+                    digit_shift: 0,
+                    lock_policy: LockPolicy::Frozen,
+                    min_hold_slots: 0,
+                    min_balance: 0,
+                };
+                3
+            ],
+            lock_programs: vec![Pubkey::default(), Pubkey::default()],
+            previous_voter_weight_plugin_program_id: Some(Pubkey::default()),
         };
 
         // Act
@@ -73,4 +289,220 @@ mod test {
         // Assert
         assert_eq!(expected_space, actual_space);
     }
+
+    fn mint_config(weight_mode: MintWeightMode, weight: u64) -> MintConfig {
+        MintConfig {
+            mint: Pubkey::new_unique(),
+            weight_mode,
+            weight,
+            digit_shift: 0,
+            lock_policy: LockPolicy::Frozen,
+            min_hold_slots: 0,
+            min_balance: 0,
+        }
+    }
+
+    fn registrar_with_mints(mints: Vec<MintConfig>) -> Registrar {
+        Registrar {
+            governance_program_id: Pubkey::default(),
+            realm: Pubkey::default(),
+            governing_token_mint: Pubkey::default(),
+            mints: vec![],
+            mint_configs: mints,
+            lock_programs: vec![],
+            previous_voter_weight_plugin_program_id: None,
+        }
+    }
+
+    #[test]
+    fn test_get_legacy_space() {
+        // Arrange
+        let expected_space = LegacyRegistrar::get_space(3);
+
+        let legacy_registrar = LegacyRegistrar {
+            governance_program_id: Pubkey::default(),
+            realm: Pubkey::default(),
+            governing_token_mint: Pubkey::default(),
+            mints: vec![Pubkey::default(); 3],
+        };
+
+        // Act
+        let actual_space = DISCRIMINATOR_SIZE + legacy_registrar.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+
+    #[test]
+    fn test_migrate_legacy_registrar() {
+        // Arrange
+        let legacy_registrar = LegacyRegistrar {
+            governance_program_id: Pubkey::new_unique(),
+            realm: Pubkey::new_unique(),
+            governing_token_mint: Pubkey::new_unique(),
+            mints: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+        };
+
+        let legacy_mints = legacy_registrar.mints.clone();
+
+        // Act
+        let registrar = legacy_registrar.migrate();
+
+        // Assert
+        assert!(registrar.mints.is_empty());
+        assert!(registrar.lock_programs.is_empty());
+        assert_eq!(registrar.previous_voter_weight_plugin_program_id, None);
+        assert_eq!(registrar.mint_configs.len(), 2);
+
+        for (mint_config, legacy_mint) in registrar.mint_configs.iter().zip(legacy_mints.iter()) {
+            assert_eq!(mint_config.mint, *legacy_mint);
+            assert_eq!(mint_config.weight_mode, MintWeightMode::Fixed);
+            assert_eq!(mint_config.weight, Registrar::LEGACY_MINT_WEIGHT);
+            assert_eq!(mint_config.lock_policy, LockPolicy::Frozen);
+            assert!(mint_config.assert_is_valid().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_get_max_voter_weight_with_fixed_mints() {
+        // Arrange
+        let registrar = registrar_with_mints(vec![
+            mint_config(MintWeightMode::Fixed, 1_000_000),
+            mint_config(MintWeightMode::Fixed, 3_000_000),
+        ]);
+
+        // Act
+        let max_voter_weight = registrar.get_max_voter_weight(&[10, 20]).unwrap();
+
+        // Assert
+        assert_eq!(max_voter_weight, 1_000_000 * 10 + 3_000_000 * 20);
+    }
+
+    #[test]
+    fn test_get_max_voter_weight_with_proportional_mints() {
+        // Arrange
+        let registrar = registrar_with_mints(vec![
+            mint_config(MintWeightMode::Proportional, 2),
+            mint_config(MintWeightMode::Fixed, 1_000_000),
+            mint_config(MintWeightMode::Proportional, 3),
+        ]);
+
+        // Act
+        let max_voter_weight = registrar.get_max_voter_weight(&[100, 5, 1_000]).unwrap();
+
+        // Assert
+        assert_eq!(max_voter_weight, 200 + 1_000_000 * 5 + 3_000);
+    }
+
+    #[test]
+    fn test_get_max_voter_weight_with_fixed_mint_overflow_error() {
+        // Arrange
+        let registrar = registrar_with_mints(vec![mint_config(MintWeightMode::Fixed, 2)]);
+
+        // Act
+        let err = registrar.get_max_voter_weight(&[u64::MAX]).err().unwrap();
+
+        // Assert
+        assert_eq!(err, error!(TokenHaverError::VoterWeightOverflow));
+    }
+
+    #[test]
+    fn test_get_max_voter_weight_with_missing_mint_supply_error() {
+        // Arrange
+        let registrar = registrar_with_mints(vec![
+            mint_config(MintWeightMode::Proportional, 2),
+            mint_config(MintWeightMode::Proportional, 3),
+        ]);
+
+        // Act
+        let err = registrar.get_max_voter_weight(&[100]).err().unwrap();
+
+        // Assert
+        assert_eq!(err, error!(TokenHaverError::MintAccountsCountMismatch));
+    }
+
+    #[test]
+    fn test_get_max_voter_weight_with_extra_mint_supply_error() {
+        // Arrange
+        let registrar = registrar_with_mints(vec![mint_config(MintWeightMode::Fixed, 1)]);
+
+        // Act
+        let err = registrar.get_max_voter_weight(&[100, 200]).err().unwrap();
+
+        // Assert
+        assert_eq!(err, error!(TokenHaverError::MintAccountsCountMismatch));
+    }
+
+    #[test]
+    fn test_upsert_mint_with_new_mint() {
+        // Arrange
+        let mut registrar =
+            registrar_with_mints(vec![mint_config(MintWeightMode::Fixed, 1_000_000)]);
+        let new_mint_config = mint_config(MintWeightMode::Proportional, 2);
+
+        // Act
+        registrar.upsert_mint(new_mint_config).unwrap();
+
+        // Assert
+        assert_eq!(registrar.mint_configs.len(), 2);
+        assert_eq!(registrar.mint_configs[1], new_mint_config);
+    }
+
+    #[test]
+    fn test_upsert_mint_with_existing_mint() {
+        // Arrange
+        let mut registrar =
+            registrar_with_mints(vec![mint_config(MintWeightMode::Fixed, 1_000_000)]);
+
+        let mut updated_mint_config = registrar.mint_configs[0];
+        updated_mint_config.weight = 5_000_000;
+
+        // Act
+        registrar.upsert_mint(updated_mint_config).unwrap();
+
+        // Assert
+        assert_eq!(registrar.mint_configs, vec![updated_mint_config]);
+    }
+
+    #[test]
+    fn test_upsert_mint_with_invalid_mint_config_error() {
+        // Arrange
+        let mut registrar = registrar_with_mints(vec![]);
+
+        let mut invalid_mint_config = mint_config(MintWeightMode::Fixed, 1_000_000);
+        invalid_mint_config.lock_policy = LockPolicy::Unlocked;
+
+        // Act
+        let err = registrar.upsert_mint(invalid_mint_config).err().unwrap();
+
+        // Assert
+        assert_eq!(err, error!(TokenHaverError::InvalidMinHoldSlots));
+    }
+
+    #[test]
+    fn test_remove_mint() {
+        // Arrange
+        let mint_config1 = mint_config(MintWeightMode::Fixed, 1_000_000);
+        let mint_config2 = mint_config(MintWeightMode::Fixed, 2_000_000);
+        let mut registrar = registrar_with_mints(vec![mint_config1, mint_config2]);
+
+        // Act
+        registrar.remove_mint(&mint_config1.mint).unwrap();
+
+        // Assert
+        assert_eq!(registrar.mint_configs, vec![mint_config2]);
+    }
+
+    #[test]
+    fn test_remove_mint_with_not_configured_mint_error() {
+        // Arrange
+        let mut registrar =
+            registrar_with_mints(vec![mint_config(MintWeightMode::Fixed, 1_000_000)]);
+
+        // Act
+        let err = registrar.remove_mint(&Pubkey::new_unique()).err().unwrap();
+
+        // Assert
+        assert_eq!(err, error!(TokenHaverError::MintNotConfigured));
+    }
 }
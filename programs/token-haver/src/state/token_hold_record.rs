@@ -0,0 +1,243 @@
+use crate::{error::TokenHaverError, id, tools::anchor::DISCRIMINATOR_SIZE};
+use anchor_lang::prelude::*;
+use solana_program::pubkey::PUBKEY_BYTES;
+
+/// TokenHoldRecord tracks for how long the balance of a token account has been held
+/// It's used to count token accounts of mints with the Unlocked lock policy
+/// There is a single TokenHoldRecord per governing_token_owner and mint and it's bound to one token account
+/// which prevents the owner from counting the same tokens again after moving them to another token account
+#[account]
+#[derive(Debug, PartialEq, Default)]
+pub struct TokenHoldRecord {
+    /// The Registrar the TokenHoldRecord belongs to
+    pub registrar: Pubkey,
+
+    /// The owner of the tracked token account
+    pub governing_token_owner: Pubkey,
+
+    /// The mint of the tracked token account
+    pub mint: Pubkey,
+
+    /// The token account the TokenHoldRecord tracks
+    pub token_account: Pubkey,
+
+    /// The balance held since hold_start_slot
+    /// It's reset with hold_start_slot whenever an update records a different balance
+    pub amount: u64,
+
+    /// The slot since when the amount has been held
+    pub hold_start_slot: u64,
+
+    /// Reserved space for future versions
+    pub reserved: [u8; 8],
+}
+
+impl TokenHoldRecord {
+    pub fn get_space() -> usize {
+        DISCRIMINATOR_SIZE + PUBKEY_BYTES * 4 + 8 + 8 + 8
+    }
+
+    /// Records the current balance of the given token account
+    /// The hold period restarts when the balance differs from the recorded amount or the record is bound to another token account
+    /// Balance changes between two updates aren't observed and a decreased balance topped up again
+    /// is only detected when the record is updated in between
+    pub fn record_balance(&mut self, token_account: &Pubkey, amount: u64, current_slot: u64) {
+        if *token_account != self.token_account || amount != self.amount {
+            self.hold_start_slot = current_slot;
+        }
+
+        self.token_account = *token_account;
+        self.amount = amount;
+    }
+
+    /// Returns the balance which has been held for at least min_hold_slots
+    /// The balance must not be lower than the recorded amount, otherwise the record is outdated
+    /// and must be reset with UpdateTokenHoldRecord before the token account can be counted again
+    pub fn get_held_amount(
+        &self,
+        amount: u64,
+        current_slot: u64,
+        min_hold_slots: u64,
+    ) -> Result<u64> {
+        require_gte!(
+            amount,
+            self.amount,
+            TokenHaverError::TokenHoldRecordOutdated
+        );
+
+        require_gte!(
+            current_slot.saturating_sub(self.hold_start_slot),
+            min_hold_slots,
+            TokenHaverError::MinHoldSlotsNotReached
+        );
+
+        Ok(amount.min(self.amount))
+    }
+}
+
+/// Returns TokenHoldRecord PDA seeds
+pub fn get_token_hold_record_seeds<'a>(
+    registrar: &'a Pubkey,
+    governing_token_owner: &'a Pubkey,
+    mint: &'a Pubkey,
+) -> [&'a [u8]; 4] {
+    [
+        b"token-hold-record",
+        registrar.as_ref(),
+        governing_token_owner.as_ref(),
+        mint.as_ref(),
+    ]
+}
+
+/// Returns TokenHoldRecord PDA address
+pub fn get_token_hold_record_address(
+    registrar: &Pubkey,
+    governing_token_owner: &Pubkey,
+    mint: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_token_hold_record_seeds(registrar, governing_token_owner, mint),
+        &id(),
+    )
+    .0
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = TokenHoldRecord::get_space();
+
+        // Act
+        let actual_space =
+            DISCRIMINATOR_SIZE + TokenHoldRecord::default().try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+
+    #[test]
+    fn test_record_balance_with_increased_balance() {
+        // Arrange
+        let mut token_hold_record = TokenHoldRecord {
+            amount: 100,
+            hold_start_slot: 10,
+            ..TokenHoldRecord::default()
+        };
+
+        // Act
+        token_hold_record.record_balance(&Pubkey::default(), 150, 20);
+
+        // Assert
+        assert_eq!(token_hold_record.amount, 150);
+        assert_eq!(token_hold_record.hold_start_slot, 20);
+    }
+
+    #[test]
+    fn test_record_balance_with_decreased_balance() {
+        // Arrange
+        let mut token_hold_record = TokenHoldRecord {
+            amount: 100,
+            hold_start_slot: 10,
+            ..TokenHoldRecord::default()
+        };
+
+        // Act
+        token_hold_record.record_balance(&Pubkey::default(), 50, 20);
+
+        // Assert
+        assert_eq!(token_hold_record.amount, 50);
+        assert_eq!(token_hold_record.hold_start_slot, 20);
+    }
+
+    #[test]
+    fn test_record_balance_with_same_balance() {
+        // Arrange
+        let mut token_hold_record = TokenHoldRecord {
+            amount: 100,
+            hold_start_slot: 10,
+            ..TokenHoldRecord::default()
+        };
+
+        // Act
+        token_hold_record.record_balance(&Pubkey::default(), 100, 20);
+
+        // Assert
+        assert_eq!(token_hold_record.amount, 100);
+        assert_eq!(token_hold_record.hold_start_slot, 10);
+    }
+
+    #[test]
+    fn test_record_balance_with_other_token_account() {
+        // Arrange
+        let mut token_hold_record = TokenHoldRecord {
+            amount: 100,
+            hold_start_slot: 10,
+            ..TokenHoldRecord::default()
+        };
+        let token_account = Pubkey::new_unique();
+
+        // Act
+        token_hold_record.record_balance(&token_account, 100, 20);
+
+        // Assert
+        assert_eq!(token_hold_record.token_account, token_account);
+        assert_eq!(token_hold_record.amount, 100);
+        assert_eq!(token_hold_record.hold_start_slot, 20);
+    }
+
+    #[test]
+    fn test_get_held_amount() {
+        // Arrange
+        let token_hold_record = TokenHoldRecord {
+            amount: 100,
+            hold_start_slot: 10,
+            ..TokenHoldRecord::default()
+        };
+
+        // Act
+        let held_amount = token_hold_record.get_held_amount(150, 20, 10).unwrap();
+
+        // Assert
+        assert_eq!(held_amount, 100);
+    }
+
+    #[test]
+    fn test_get_held_amount_with_decreased_balance_error() {
+        // Arrange
+        let token_hold_record = TokenHoldRecord {
+            amount: 100,
+            hold_start_slot: 10,
+            ..TokenHoldRecord::default()
+        };
+
+        // Act
+        let err = token_hold_record.get_held_amount(99, 20, 10).err().unwrap();
+
+        // Assert
+        assert_eq!(err, error!(TokenHaverError::TokenHoldRecordOutdated));
+    }
+
+    #[test]
+    fn test_get_held_amount_with_min_hold_slots_not_reached_error() {
+        // Arrange
+        let token_hold_record = TokenHoldRecord {
+            amount: 100,
+            hold_start_slot: 10,
+            ..TokenHoldRecord::default()
+        };
+
+        // Act
+        let err = token_hold_record
+            .get_held_amount(100, 19, 10)
+            .err()
+            .unwrap();
+
+        // Assert
+        assert_eq!(err, error!(TokenHaverError::MinHoldSlotsNotReached));
+    }
+}
@@ -1,11 +1,13 @@
 use anchor_lang::prelude::*;
+use gpl_shared::compose::VoterWeightRecordBase;
+use num_derive::FromPrimitive;
 use solana_program::pubkey::PUBKEY_BYTES;
 
 use crate::tools::anchor::DISCRIMINATOR_SIZE;
 
 /// VoterWeightAction enum as defined in spl-governance-addin-api
 /// It's redefined here for Anchor to export it to IDL
-#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, FromPrimitive)]
 pub enum VoterWeightAction {
     /// Cast vote for a proposal. Target: Proposal
     CastVote,
@@ -75,6 +77,16 @@ impl VoterWeightRecord {
     }
 }
 
+impl<'a> VoterWeightRecordBase<'a> for VoterWeightRecord {
+    fn get_governing_token_mint(&'a self) -> &'a Pubkey {
+        &self.governing_token_mint
+    }
+
+    fn get_governing_token_owner(&'a self) -> &'a Pubkey {
+        &self.governing_token_owner
+    }
+}
+
 impl Default for VoterWeightRecord {
     fn default() -> Self {
         Self {
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use std::cmp::Ordering;
+
+/// Resizes the program owned account to new_size and keeps it rent exempt
+/// The payer funds the rent when the account grows and the excess rent is refunded to lamports_destination when it shrinks
+pub fn resize_account<'info>(
+    account_info: &AccountInfo<'info>,
+    new_size: usize,
+    payer: &AccountInfo<'info>,
+    lamports_destination: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let new_minimum_balance = Rent::get()?.minimum_balance(new_size);
+    let current_balance = account_info.lamports();
+
+    match new_minimum_balance.cmp(&current_balance) {
+        Ordering::Greater => {
+            let cpi_accounts = Transfer {
+                from: payer.clone(),
+                to: account_info.clone(),
+            };
+            let cpi_ctx = CpiContext::new(system_program.clone(), cpi_accounts);
+
+            system_program::transfer(cpi_ctx, new_minimum_balance - current_balance)?;
+        }
+        Ordering::Less => {
+            let refund = current_balance - new_minimum_balance;
+
+            **account_info.try_borrow_mut_lamports()? = new_minimum_balance;
+            **lamports_destination.try_borrow_mut_lamports()? =
+                lamports_destination.lamports().checked_add(refund).unwrap();
+        }
+        Ordering::Equal => {}
+    }
+
+    account_info.realloc(new_size, false)?;
+
+    Ok(())
+}
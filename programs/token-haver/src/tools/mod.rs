@@ -1 +1,3 @@
+pub mod account;
 pub mod anchor;
+pub mod token;
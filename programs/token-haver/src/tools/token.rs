@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{
+        non_transferable::NonTransferableAccount, BaseStateWithExtensions, StateWithExtensions,
+    },
+    state::Account,
+};
+
+/// Returns true if the token account holds a Token-2022 NonTransferable mint and can't be transferred by its owner
+///
+/// Note: The data of both spl-token and Token-2022 accounts can be unpacked as Token-2022 accounts
+pub fn is_token_account_non_transferable(token_account_data: &[u8]) -> Result<bool> {
+    let token_account = StateWithExtensions::<Account>::unpack(token_account_data)?;
+
+    Ok(token_account
+        .get_extension::<NonTransferableAccount>()
+        .is_ok())
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use anchor_spl::token_2022::spl_token_2022::{
+        extension::{BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut},
+        state::AccountState,
+    };
+    use solana_program::program_pack::Pack;
+
+    fn token_account_data(state: AccountState, non_transferable: bool) -> Vec<u8> {
+        let extensions = if non_transferable {
+            vec![ExtensionType::NonTransferableAccount]
+        } else {
+            vec![]
+        };
+
+        let account_len = ExtensionType::try_calculate_account_len::<Account>(&extensions).unwrap();
+        let mut data = vec![0; account_len];
+
+        let base = Account {
+            mint: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            amount: 1,
+            state,
+            ..Account::default()
+        };
+
+        if extensions.is_empty() {
+            Account::pack(base, &mut data).unwrap();
+        } else {
+            let mut token_account =
+                StateWithExtensionsMut::<Account>::unpack_uninitialized(&mut data).unwrap();
+            token_account
+                .init_extension::<NonTransferableAccount>(true)
+                .unwrap();
+            token_account.base = base;
+            token_account.pack_base();
+            token_account.init_account_type().unwrap();
+        }
+
+        data
+    }
+
+    #[test]
+    fn test_is_token_account_non_transferable_with_frozen_account() {
+        // Arrange
+        let data = token_account_data(AccountState::Frozen, false);
+
+        // Act
+        let is_non_transferable = is_token_account_non_transferable(&data).unwrap();
+
+        // Assert
+        assert!(!is_non_transferable);
+    }
+
+    #[test]
+    fn test_is_token_account_non_transferable_with_initialized_account() {
+        // Arrange
+        let data = token_account_data(AccountState::Initialized, false);
+
+        // Act
+        let is_non_transferable = is_token_account_non_transferable(&data).unwrap();
+
+        // Assert
+        assert!(!is_non_transferable);
+    }
+
+    #[test]
+    fn test_is_token_account_non_transferable_with_non_transferable_account() {
+        // Arrange
+        let data = token_account_data(AccountState::Initialized, true);
+
+        // Act
+        let is_non_transferable = is_token_account_non_transferable(&data).unwrap();
+
+        // Assert
+        assert!(is_non_transferable);
+    }
+}
@@ -102,4 +102,58 @@ pub enum TokenVoterError {
 
     #[msg("Inactive Deposit Index!")]
     DepositIndexInactive,
+
+    #[msg("Invalid lockup period")]
+    InvalidLockupPeriod,
+
+    #[msg("Lockup can't be shortened or changed to a weaker kind")]
+    InvalidLockupChange,
+
+    #[msg("Unlocked amount is insufficient for the withdrawal")]
+    InsufficientUnlockedTokens,
+
+    #[msg("Lockup saturation must be positive when extra lockup vote weight is configured")]
+    InvalidLockupSaturation,
+
+    #[msg("Number of mint accounts doesn't match the configured voting mints")]
+    InvalidVotingMintAccountsCount,
+
+    #[msg("Mint account doesn't match the configured voting mint")]
+    InvalidVotingMintAccount,
+
+    #[msg("Weight multiplier denominator must be positive")]
+    InvalidWeightMultiplier,
+
+    #[msg("Voting mint is retired")]
+    VotingMintRetired,
+
+    #[msg("Voter has already delegated the vote weight")]
+    VoterAlreadyDelegated,
+
+    #[msg("Voter hasn't delegated the vote weight")]
+    VoterNotDelegated,
+
+    #[msg("Voter must clear the delegate first")]
+    VoterDelegated,
+
+    #[msg("Invalid delegate")]
+    InvalidDelegate,
+
+    #[msg("DelegatedVoterWeight of the voter's delegate is missing")]
+    MissingDelegatedVoterWeight,
+
+    #[msg("Invalid DelegatedVoterWeight")]
+    InvalidDelegatedVoterWeight,
+
+    #[msg("Invalid TokenOwnerRecord of the delegate")]
+    InvalidDelegateTokenOwnerRecord,
+
+    #[msg("Delegator doesn't delegate the vote weight to the voter")]
+    InvalidDelegator,
+
+    #[msg("Duplicate delegator")]
+    DuplicateDelegator,
+
+    #[msg("Delegators count overflow")]
+    DelegatorsCountOverflow,
 }
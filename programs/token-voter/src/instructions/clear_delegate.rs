@@ -0,0 +1,102 @@
+use {
+    crate::{error::*, state::*},
+    anchor_lang::prelude::*,
+    spl_governance::state::token_owner_record,
+};
+
+#[derive(Accounts)]
+pub struct ClearDelegate<'info> {
+    pub registrar: Box<Account<'info, Registrar>>,
+
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter".as_ref(), voter_authority.key().as_ref()],
+        bump = voter.voter_bump,
+        has_one = registrar,
+        has_one = voter_authority,
+    )]
+    pub voter: Box<Account<'info, Voter>>,
+
+    pub voter_authority: Signer<'info>,
+
+    /// The vote weight is restored to the delegator
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter-weight-record".as_ref(), voter_authority.key().as_ref()],
+        bump = voter.voter_weight_record_bump,
+        constraint = voter_weight_record.realm == registrar.realm
+        @ TokenVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ TokenVoterError::InvalidVoterWeightRecordMint,
+    )]
+    pub voter_weight_record: Box<Account<'info, VoterWeightRecord>>,
+
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"delegated-voter-weight".as_ref(), voter.delegate.as_ref()],
+        bump = delegated_voter_weight.bump,
+        has_one = registrar @ TokenVoterError::InvalidDelegatedVoterWeight,
+    )]
+    pub delegated_voter_weight: Box<Account<'info, DelegatedVoterWeight>>,
+
+    /// TokenOwnerRecord of the delegate
+    /// The delegated vote weight can't be taken back while the delegate has outstanding votes
+    /// or open proposals, the same way the voter couldn't withdraw its own tokens
+    ///
+    /// CHECK: Validated in the instruction, it can be uninitialized if the delegate never voted
+    pub delegate_token_owner_record: UncheckedAccount<'info>,
+}
+
+/// Clears the delegate and restores the vote weight to the voter.
+pub fn clear_delegate(ctx: Context<ClearDelegate>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let voter = &mut ctx.accounts.voter;
+
+    require!(voter.is_delegated(), TokenVoterError::VoterNotDelegated);
+
+    let delegate_token_owner_record_info = &ctx.accounts.delegate_token_owner_record;
+
+    require_keys_eq!(
+        delegate_token_owner_record_info.key(),
+        token_owner_record::get_token_owner_record_address(
+            &registrar.governance_program_id,
+            &registrar.realm,
+            &registrar.governing_token_mint,
+            &voter.delegate,
+        ),
+        TokenVoterError::InvalidDelegateTokenOwnerRecord
+    );
+
+    if !delegate_token_owner_record_info.data_is_empty() {
+        let delegate_token_owner_record = voter.load_token_owner_record(
+            &delegate_token_owner_record_info.to_account_info(),
+            registrar,
+            &voter.delegate,
+        )?;
+        delegate_token_owner_record.assert_can_withdraw_governing_tokens()?;
+    }
+
+    let delegated_voter_weight = &mut ctx.accounts.delegated_voter_weight;
+    delegated_voter_weight.update_voter_weight(voter.delegated_voter_weight, 0)?;
+    delegated_voter_weight.delegators_count = delegated_voter_weight
+        .delegators_count
+        .checked_sub(1)
+        .ok_or_else(|| error!(TokenVoterError::DelegatorsCountOverflow))?;
+
+    voter.delegate = Pubkey::default();
+    voter.delegated_voter_weight = 0;
+
+    let clock = Clock::get()?;
+
+    let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+    voter_weight_record.voter_weight = voter.weight(registrar)?;
+    voter_weight_record.voter_weight_expiry =
+        voter.voter_weight_expiry(registrar, clock.unix_timestamp, clock.slot);
+
+    // Set action and target to None to indicate the weight is valid for any action and target
+    voter_weight_record.weight_action = None;
+    voter_weight_record.weight_action_target = None;
+
+    Ok(())
+}
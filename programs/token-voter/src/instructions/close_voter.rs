@@ -51,6 +51,7 @@ pub struct CloseVoter<'info> {
 pub fn close_voter<'info>(ctx: Context<'_, '_, '_, 'info, CloseVoter<'info>>) -> Result<()> {
     let voter = &ctx.accounts.voter;
     let voter_authority = &ctx.accounts.voter_authority;
+    require!(!voter.is_delegated(), TokenVoterError::VoterDelegated);
     let amount = voter.deposits.iter().fold(0u64, |sum, d| {
         sum.checked_add(d.amount_deposited_native).unwrap()
     });
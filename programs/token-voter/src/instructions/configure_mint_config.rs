@@ -44,9 +44,21 @@ pub struct ConfigureVotingMintConfig<'info> {
     pub governance_program_id: UncheckedAccount<'info>,
 }
 
+/// Creates or updates the VotingMintConfig for the given mint
+///
+/// `digit_shift`: Number of digits to shift native amounts to get the baseline vote weight
+/// `lockup_saturation_secs`: Remaining lockup time at which the extra lockup vote weight is maxed out
+/// `max_extra_lockup_vote_weight_scaled_factor`: Extra vote weight factor of fully saturated
+/// locked tokens in 1/SCALED_FACTOR_BASE units
+/// `weight_multiplier_numerator`, `weight_multiplier_denominator`: Multiplier applied to
+/// the digit shifted amounts, ex. 5/2 for 1 token = 2.5 votes
 pub fn configure_mint_config(
     ctx: Context<ConfigureVotingMintConfig>,
     digit_shift: i8,
+    lockup_saturation_secs: u64,
+    max_extra_lockup_vote_weight_scaled_factor: u64,
+    weight_multiplier_numerator: u64,
+    weight_multiplier_denominator: u64,
 ) -> Result<()> {
     let registrar = &mut ctx.accounts.registrar;
     let mint = &ctx.accounts.mint;
@@ -68,20 +80,43 @@ pub fn configure_mint_config(
         mint: mint.key(),
         digit_shift,
         mint_supply: mint.supply,
-        reserved1: [0; 55],
+        lockup_saturation_secs,
+        max_extra_lockup_vote_weight_scaled_factor,
+        weight_multiplier_numerator,
+        weight_multiplier_denominator,
+        total_deposited_native: 0,
+        retired: false,
+        total_deposited_native_tracked: true,
+        reserved1: [0; 13],
     };
 
+    voting_mint_config.assert_is_valid()?;
+
     let mint_config_idx = registrar
         .voting_mint_configs
         .iter()
         .position(|vmc| vmc.mint == mint.key());
 
     if let Some(mint_config_idx) = mint_config_idx {
-        registrar.voting_mint_configs[mint_config_idx] = voting_mint_config;
+        // Reconfiguring a retired mint makes it active again
+        // and the deposits held in the voter vaults are carried over
+        let current_config = &registrar.voting_mint_configs[mint_config_idx];
+
+        registrar.voting_mint_configs[mint_config_idx] = VotingMintConfig {
+            total_deposited_native: current_config.total_deposited_native,
+            total_deposited_native_tracked: current_config.total_deposited_native_tracked,
+            ..voting_mint_config
+        };
     } else {
-        // Note: In the current runtime version push() would throw an error if we exceed
-        // max_mints specified when the Registrar was created
-        registrar.voting_mint_configs.push(voting_mint_config);
+        let mint_config_idx = registrar.free_voting_mint_config_index();
+
+        if mint_config_idx < registrar.voting_mint_configs.len() {
+            registrar.voting_mint_configs[mint_config_idx] = voting_mint_config;
+        } else {
+            // Note: In the current runtime version push() would throw an error if we exceed
+            // max_mints specified when the Registrar was created
+            registrar.voting_mint_configs.push(voting_mint_config);
+        }
     }
 
     // Update MaxVoterWeightRecord.max_voter_weight
@@ -15,6 +15,7 @@ use {
 /// Deposits and creates vault based on the tokens configured in mint_configs
 #[derive(Accounts)]
 pub struct Deposit<'info> {
+    #[account(mut)]
     pub registrar: Box<Account<'info, Registrar>>,
 
     #[account(
@@ -69,6 +70,11 @@ pub struct Deposit<'info> {
     /// CHECK: Address constraint is set
     #[account(address = tx_instructions::ID)]
     pub instructions: UncheckedAccount<'info>,
+
+    /// DelegatedVoterWeight of the voter's delegate
+    /// Optional, when provided the informational delegated weight is kept up to date
+    #[account(mut)]
+    pub delegated_voter_weight: Option<Box<Account<'info, DelegatedVoterWeight>>>,
 }
 
 /// Adds tokens to a deposit entry.
@@ -102,7 +108,7 @@ pub fn deposit<'info>(
         )?;
     }
 
-    let registrar = &ctx.accounts.registrar;
+    let registrar = &mut ctx.accounts.registrar;
     let voter = &mut ctx.accounts.voter;
 
     let deposit_entry = match voter.active_deposit_mut(deposit_entry_index) {
@@ -118,8 +124,14 @@ pub fn deposit<'info>(
         .unwrap();
 
     // Get the exchange rate entry associated with this deposit,
-    // fails if registrar.voting_mint_configs does not exist.
-    let mint_idx = registrar.voting_mint_config_index(ctx.accounts.deposit_token.mint)?;
+    // fails if registrar.voting_mint_configs does not exist or the mint is retired.
+    let mint_idx = registrar.active_voting_mint_config_index(ctx.accounts.deposit_token.mint)?;
+
+    let voting_mint_config = &mut registrar.voting_mint_configs[mint_idx];
+    voting_mint_config.total_deposited_native = voting_mint_config
+        .total_deposited_native
+        .checked_add(deposit_amount)
+        .unwrap();
 
     require_eq!(
         mint_idx,
@@ -129,6 +141,7 @@ pub fn deposit<'info>(
 
     let clock = Clock::get()?;
     let current_slot_hash = clock.slot;
+    let curr_ts = clock.unix_timestamp;
 
     match deposit_entry {
         Some(d_entry) => {
@@ -138,11 +151,23 @@ pub fn deposit<'info>(
                 TokenVoterError::MintIndexMismatch
             );
 
+            // Track only the tokens which are still locked and
+            // move Daily lockups to the start of the current period
+            d_entry.resolve_vesting(curr_ts)?;
+
             d_entry.amount_deposited_native = d_entry
                 .amount_deposited_native
                 .checked_add(deposit_amount)
                 .unwrap();
 
+            // Tokens added to a locked deposit are locked up for the remainder of the lockup
+            if d_entry.lockup.kind != LockupKind::None {
+                d_entry.amount_initially_locked_native = d_entry
+                    .amount_initially_locked_native
+                    .checked_add(deposit_amount)
+                    .unwrap();
+            }
+
             // Deposit is only valid as of the current slot
             d_entry.deposit_slot_hash = current_slot_hash;
             d_entry.is_used = true;
@@ -153,7 +178,9 @@ pub fn deposit<'info>(
                 amount_deposited_native: deposit_amount,
                 voting_mint_config_idx: mint_idx as u8,
                 is_used: true,
-                reserved: [0; 38],
+                lockup: Lockup::default(),
+                amount_initially_locked_native: 0,
+                reserved: [0; 6],
             };
             voter.deposits[mint_idx] = deposit_entry;
         }
@@ -161,7 +188,7 @@ pub fn deposit<'info>(
 
     let voter_weight_record = &mut ctx.accounts.voter_weight_record;
 
-    let governance_program_id = &ctx.accounts.registrar.governance_program_id;
+    let governance_program_id = &registrar.governance_program_id;
 
     let token_owner_record = token_owner_record::get_token_owner_record_data(
         governance_program_id,
@@ -175,12 +202,21 @@ pub fn deposit<'info>(
         TokenVoterError::GoverningTokenOwnerMustMatch
     );
 
+    voter.update_delegated_voter_weight(
+        registrar,
+        ctx.accounts
+            .delegated_voter_weight
+            .as_deref_mut()
+            .map(|dvw| &mut **dvw),
+    )?;
+
     // Setup voter_weight
-    voter_weight_record.voter_weight = voter.weight(registrar)?;
+    voter_weight_record.voter_weight = voter.undelegated_weight(registrar)?;
 
-    // Voter Weight Expiry is always set to None after a deposit
-    // since no other action other than deposit and withdraw could invalidate it
-    voter_weight_record.voter_weight_expiry = None;
+    // Voter Weight Expiry is set to None after a deposit unless the weight
+    // decays with the remaining lockup time and must be refreshed before it's used
+    voter_weight_record.voter_weight_expiry =
+        voter.voter_weight_expiry(registrar, curr_ts, current_slot_hash);
 
     // Set action and target to None to indicate the weight is valid for any action and target
     voter_weight_record.weight_action = None;
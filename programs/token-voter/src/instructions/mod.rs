@@ -1,6 +1,9 @@
 pub use configure_mint_config::*;
 mod configure_mint_config;
 
+pub use remove_mint_config::*;
+mod remove_mint_config;
+
 pub use create_registrar::*;
 mod create_registrar;
 
@@ -13,6 +16,9 @@ mod create_voter_weight_record;
 pub use create_max_voter_weight_record::*;
 mod create_max_voter_weight_record;
 
+pub use update_max_voter_weight_record::*;
+mod update_max_voter_weight_record;
+
 pub use close_voter::*;
 mod close_voter;
 
@@ -21,3 +27,15 @@ mod withdraw;
 
 pub use deposit::*;
 mod deposit;
+
+pub use reset_lockup::*;
+mod reset_lockup;
+
+pub use update_voter_weight_record::*;
+mod update_voter_weight_record;
+
+pub use set_delegate::*;
+mod set_delegate;
+
+pub use clear_delegate::*;
+mod clear_delegate;
@@ -0,0 +1,82 @@
+use {
+    crate::{error::*, state::*},
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::Mint,
+    spl_governance::state::realm,
+};
+
+/// Retires a voting mint and frees its slot once all deposits are withdrawn
+#[derive(Accounts)]
+pub struct RemoveMintConfig<'info> {
+    /// Registrar which the voting mint is removed from
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+       owner = registrar.governance_program_id,
+       constraint = realm.key() == registrar.realm @ TokenVoterError::InvalidRealmForRegistrar,
+    )]
+    /// CHECK: Owned by spl-governance instance specified in registrar.governance_program_id
+    // Validated below
+    pub realm: UncheckedAccount<'info>,
+
+    /// Authority of the Realm must sign the transaction and must match realm.authority
+    pub realm_authority: Signer<'info>,
+
+    /// Tokens of this mint will no longer grant any vote weight
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ TokenVoterError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ TokenVoterError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+/// Removes the VotingMintConfig for the given mint
+///
+/// The mint is retired first: it no longer accepts deposits, it doesn't contribute to the vote weight
+/// and existing deposits can be withdrawn regardless of their lockups.
+/// Once all deposits are withdrawn the instruction can be executed again to free the slot.
+/// The slot is reset in place and reused by the next configured mint
+/// so voting_mint_config_idx of the other deposits stays valid.
+///
+/// Slots of mints configured before total_deposited_native was tracked are never freed
+/// because their deposits can't be accounted for and must remain withdrawable.
+pub fn remove_mint_config(ctx: Context<RemoveMintConfig>) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let realm = realm::get_realm_data_for_governing_token_mint(
+        &registrar.governance_program_id,
+        &ctx.accounts.realm,
+        &registrar.governing_token_mint,
+    )?;
+
+    require_eq!(
+        realm.authority.unwrap(),
+        ctx.accounts.realm_authority.key(),
+        TokenVoterError::InvalidRealmAuthority
+    );
+
+    let mint_config_idx = registrar.voting_mint_config_index(ctx.accounts.mint.key())?;
+    let voting_mint_config = &mut registrar.voting_mint_configs[mint_config_idx];
+
+    voting_mint_config.retired = true;
+
+    if !voting_mint_config.has_deposits() {
+        *voting_mint_config = VotingMintConfig::default();
+    }
+
+    // Update MaxVoterWeightRecord.max_voter_weight
+    // the retired mint no longer contributes to it
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+    max_voter_weight_record.max_voter_weight = registrar.max_vote_weight()?;
+
+    max_voter_weight_record.max_voter_weight_expiry = None;
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use {
+    crate::{error::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct ResetLockup<'info> {
+    pub registrar: Box<Account<'info, Registrar>>,
+
+    // Checking the PDA address it just an extra precaution,
+    // the other constraints must be exhaustive
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter".as_ref(), voter_authority.key().as_ref()],
+        bump = voter.voter_bump,
+        has_one = registrar,
+        has_one = voter_authority,
+    )]
+    pub voter: Box<Account<'info, Voter>>,
+
+    pub voter_authority: Signer<'info>,
+
+    /// The lockup changes the voter weight so the record must be updated
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter-weight-record".as_ref(), voter_authority.key().as_ref()],
+        bump = voter.voter_weight_record_bump,
+        constraint = voter_weight_record.realm == registrar.realm
+        @ TokenVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ TokenVoterError::InvalidVoterWeightRecordMint,
+    )]
+    pub voter_weight_record: Box<Account<'info, VoterWeightRecord>>,
+
+    /// DelegatedVoterWeight of the voter's delegate
+    /// Optional, when provided the informational delegated weight is kept up to date
+    #[account(mut)]
+    pub delegated_voter_weight: Option<Box<Account<'info, DelegatedVoterWeight>>>,
+}
+
+/// Locks up the whole deposit entry starting now.
+///
+/// The new lockup can't end before the current one and can't be weaker than it:
+/// None < Daily < Cliff = Constant. A Constant lockup can be reset to a Cliff lockup
+/// of at least the same duration to start unlocking.
+///
+/// `deposit_entry_index`: Index of the deposit entry.
+/// `kind`: Kind of the new lockup.
+/// `periods`: Number of days the new lockup lasts for.
+pub fn reset_lockup(
+    ctx: Context<ResetLockup>,
+    deposit_entry_index: u8,
+    kind: LockupKind,
+    periods: u32,
+) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let voter = &mut ctx.accounts.voter;
+
+    let clock = Clock::get()?;
+    let curr_ts = clock.unix_timestamp;
+
+    let deposit_entry = voter.active_deposit_mut(deposit_entry_index)?;
+
+    // Only the tokens which are still locked are accounted for in the current lockup
+    deposit_entry.resolve_vesting(curr_ts)?;
+
+    let lockup = if kind == LockupKind::None {
+        Lockup::default()
+    } else {
+        Lockup::new_from_periods(kind, curr_ts, periods)?
+    };
+
+    require_gte!(
+        lockup.kind.strictness(),
+        deposit_entry.lockup.kind.strictness(),
+        TokenVoterError::InvalidLockupChange
+    );
+
+    require_gte!(
+        lockup.seconds_left(curr_ts),
+        deposit_entry.lockup.seconds_left(curr_ts),
+        TokenVoterError::InvalidLockupChange
+    );
+
+    deposit_entry.amount_initially_locked_native = if lockup.kind == LockupKind::None {
+        0
+    } else {
+        deposit_entry.amount_deposited_native
+    };
+    deposit_entry.lockup = lockup;
+
+    voter.update_delegated_voter_weight(
+        registrar,
+        ctx.accounts
+            .delegated_voter_weight
+            .as_deref_mut()
+            .map(|dvw| &mut **dvw),
+    )?;
+
+    let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+    voter_weight_record.voter_weight = voter.undelegated_weight(registrar)?;
+    voter_weight_record.voter_weight_expiry =
+        voter.voter_weight_expiry(registrar, curr_ts, clock.slot);
+
+    // Set action and target to None to indicate the weight is valid for any action and target
+    voter_weight_record.weight_action = None;
+    voter_weight_record.weight_action_target = None;
+
+    Ok(())
+}
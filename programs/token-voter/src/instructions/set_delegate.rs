@@ -0,0 +1,116 @@
+use {
+    crate::{error::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct SetDelegate<'info> {
+    pub registrar: Box<Account<'info, Registrar>>,
+
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter".as_ref(), voter_authority.key().as_ref()],
+        bump = voter.voter_bump,
+        has_one = registrar,
+        has_one = voter_authority,
+    )]
+    pub voter: Box<Account<'info, Voter>>,
+
+    #[account(mut)]
+    pub voter_authority: Signer<'info>,
+
+    /// The token_owner_record for the voter_authority
+    /// The vote weight can't be delegated while the voter is engaged with a vote or has an open proposal,
+    /// otherwise the delegate could use the same weight again
+    ///
+    /// CHECK: token_owner_record is validated in the instruction:
+    /// - owned by registrar.governance_program_id
+    /// - for the registrar.realm
+    /// - for the registrar.realm_governing_token_mint
+    /// - governing_token_owner is voter_authority
+    pub token_owner_record: UncheckedAccount<'info>,
+
+    /// The delegator's vote weight drops to zero once delegated
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter-weight-record".as_ref(), voter_authority.key().as_ref()],
+        bump = voter.voter_weight_record_bump,
+        constraint = voter_weight_record.realm == registrar.realm
+        @ TokenVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ TokenVoterError::InvalidVoterWeightRecordMint,
+    )]
+    pub voter_weight_record: Box<Account<'info, VoterWeightRecord>>,
+
+    /// CHECK: The delegate can be any wallet other than the voter authority
+    pub delegate: UncheckedAccount<'info>,
+
+    /// The aggregate of the vote weight delegated to the delegate
+    #[account(
+        init_if_needed,
+        seeds = [registrar.key().as_ref(), b"delegated-voter-weight".as_ref(), delegate.key().as_ref()],
+        bump,
+        payer = voter_authority,
+        space = DelegatedVoterWeight::get_space(),
+    )]
+    pub delegated_voter_weight: Box<Account<'info, DelegatedVoterWeight>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Delegates the vote weight of all the voter's deposits to the delegate.
+///
+/// The vote weight is added to the delegate's DelegatedVoterWeight
+/// and the delegator's VoterWeightRecord drops to zero.
+/// The deposits stay owned by the voter but can't be withdrawn until the delegate is cleared.
+pub fn set_delegate(ctx: Context<SetDelegate>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let voter = &mut ctx.accounts.voter;
+    let delegate = ctx.accounts.delegate.key();
+
+    require!(
+        !voter.is_delegated(),
+        TokenVoterError::VoterAlreadyDelegated
+    );
+
+    require!(
+        delegate != voter.voter_authority && delegate != Pubkey::default(),
+        TokenVoterError::InvalidDelegate
+    );
+
+    let token_owner_record = voter.load_token_owner_record(
+        &ctx.accounts.token_owner_record.to_account_info(),
+        registrar,
+        &voter.voter_authority,
+    )?;
+    token_owner_record.assert_can_withdraw_governing_tokens()?;
+
+    let delegated_voter_weight = &mut ctx.accounts.delegated_voter_weight;
+
+    // Initialize the aggregate when the delegate receives its first delegation
+    if delegated_voter_weight.delegate == Pubkey::default() {
+        delegated_voter_weight.registrar = registrar.key();
+        delegated_voter_weight.delegate = delegate;
+        delegated_voter_weight.bump = ctx.bumps.delegated_voter_weight;
+    }
+
+    delegated_voter_weight.delegators_count = delegated_voter_weight
+        .delegators_count
+        .checked_add(1)
+        .ok_or_else(|| error!(TokenVoterError::DelegatorsCountOverflow))?;
+
+    voter.delegate = delegate;
+    voter.delegated_voter_weight = 0;
+    voter.update_delegated_voter_weight(registrar, Some(delegated_voter_weight))?;
+
+    let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+    voter_weight_record.voter_weight = voter.undelegated_weight(registrar)?;
+    voter_weight_record.voter_weight_expiry = None;
+
+    // Set action and target to None to indicate the weight is valid for any action and target
+    voter_weight_record.weight_action = None;
+    voter_weight_record.weight_action_target = None;
+
+    Ok(())
+}
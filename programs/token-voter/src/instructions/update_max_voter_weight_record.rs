@@ -0,0 +1,73 @@
+use {
+    crate::{error::*, state::*},
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::Mint,
+};
+
+/// Refreshes the MaxVoterWeightRecord from the current supply of the voting mints
+#[derive(Accounts)]
+pub struct UpdateMaxVoterWeightRecord<'info> {
+    /// The Registrar the MaxVoterWeightRecord account belongs to
+    #[account(mut)]
+    pub registrar: Account<'info, Registrar>,
+
+    #[account(
+        mut,
+        seeds = [ b"max-voter-weight-record".as_ref(),
+                registrar.realm.key().as_ref(),
+                registrar.governing_token_mint.key().as_ref()],
+        bump,
+        constraint = max_voter_weight_record.realm == registrar.realm
+        @ TokenVoterError::InvalidMaxVoterWeightRecordRealm,
+
+        constraint = max_voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ TokenVoterError::InvalidMaxVoterWeightRecordMint,
+    )]
+    pub max_voter_weight_record: Account<'info, MaxVoterWeightRecord>,
+}
+
+/// Updates VotingMintConfig.mint_supply of every configured voting mint from the live mint supply
+/// and recalculates MaxVoterWeightRecord.max_voter_weight
+///
+/// The instruction is permissionless and the mints of all active (configured and not retired) voting mints
+/// must be provided as remaining accounts in the order of registrar.voting_mint_configs
+pub fn update_max_voter_weight_record<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateMaxVoterWeightRecord<'info>>,
+) -> Result<()> {
+    let registrar = &mut ctx.accounts.registrar;
+
+    let voting_mint_configs = registrar
+        .voting_mint_configs
+        .iter_mut()
+        .filter(|vmc| vmc.is_active())
+        .collect::<Vec<_>>();
+
+    require_eq!(
+        voting_mint_configs.len(),
+        ctx.remaining_accounts.len(),
+        TokenVoterError::InvalidVotingMintAccountsCount
+    );
+
+    for (voting_mint_config, mint_info) in voting_mint_configs
+        .into_iter()
+        .zip(ctx.remaining_accounts.iter())
+    {
+        require_keys_eq!(
+            voting_mint_config.mint,
+            mint_info.key(),
+            TokenVoterError::InvalidVotingMintAccount
+        );
+
+        let mint = InterfaceAccount::<Mint>::try_from(mint_info)?;
+        voting_mint_config.mint_supply = mint.supply;
+    }
+
+    let max_voter_weight_record = &mut ctx.accounts.max_voter_weight_record;
+    max_voter_weight_record.max_voter_weight = registrar.max_vote_weight()?;
+
+    // The supply can change at any time but the record is as accurate as the last refresh
+    // and it's the responsibility of the realm to keep it up to date
+    max_voter_weight_record.max_voter_weight_expiry = None;
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use {
+    crate::{error::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct UpdateVoterWeightRecord<'info> {
+    pub registrar: Box<Account<'info, Registrar>>,
+
+    #[account(mut, has_one = registrar)]
+    pub voter: Box<Account<'info, Voter>>,
+
+    #[account(
+        mut,
+        seeds = [registrar.key().as_ref(), b"voter-weight-record".as_ref(), voter.voter_authority.as_ref()],
+        bump = voter.voter_weight_record_bump,
+        constraint = voter_weight_record.realm == registrar.realm
+        @ TokenVoterError::InvalidVoterWeightRecordRealm,
+
+        constraint = voter_weight_record.governing_token_mint == registrar.governing_token_mint
+        @ TokenVoterError::InvalidVoterWeightRecordMint,
+    )]
+    pub voter_weight_record: Box<Account<'info, VoterWeightRecord>>,
+
+    /// DelegatedVoterWeight of the voter's delegate
+    /// Optional, when provided the informational delegated weight is kept up to date
+    #[account(mut)]
+    pub delegated_voter_weight: Option<Box<Account<'info, DelegatedVoterWeight>>>,
+}
+
+/// Refreshes the VoterWeightRecord with the current voter weight.
+///
+/// The extra vote weight of locked deposits decays with the remaining lockup time
+/// and the record must be refreshed in the same slot it's used in.
+///
+/// The Voter accounts of the voters delegating to the voter are passed as remaining accounts
+/// and their vote weight is computed at the current time and added to the voter weight.
+/// Since the delegators can change it at any time the record is then only valid for the current slot as well.
+/// The instruction is permissionless since it only recomputes the weight from the Voter accounts.
+pub fn update_voter_weight_record(ctx: Context<UpdateVoterWeightRecord>) -> Result<()> {
+    let registrar = &ctx.accounts.registrar;
+    let voter = &mut ctx.accounts.voter;
+
+    voter.update_delegated_voter_weight(
+        registrar,
+        ctx.accounts
+            .delegated_voter_weight
+            .as_deref_mut()
+            .map(|dvw| &mut **dvw),
+    )?;
+
+    let mut delegators: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+    let mut received_voter_weight = 0u64;
+
+    for delegator_info in ctx.remaining_accounts {
+        require!(
+            !delegators.contains(delegator_info.key),
+            TokenVoterError::DuplicateDelegator
+        );
+        delegators.push(delegator_info.key());
+
+        require_keys_eq!(
+            *delegator_info.owner,
+            crate::id(),
+            TokenVoterError::InvalidDelegator
+        );
+        let delegator = Voter::try_deserialize(&mut &delegator_info.try_borrow_data()?[..])?;
+
+        require_keys_eq!(
+            delegator.registrar,
+            registrar.key(),
+            TokenVoterError::InvalidDelegator
+        );
+        require_keys_eq!(
+            delegator.delegate,
+            voter.voter_authority,
+            TokenVoterError::InvalidDelegator
+        );
+
+        received_voter_weight = received_voter_weight
+            .checked_add(delegator.weight(registrar)?)
+            .ok_or_else(|| error!(TokenVoterError::VoterWeightOverflow))?;
+    }
+
+    let clock = Clock::get()?;
+
+    let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+    voter_weight_record.voter_weight = voter
+        .undelegated_weight(registrar)?
+        .checked_add(received_voter_weight)
+        .ok_or_else(|| error!(TokenVoterError::VoterWeightOverflow))?;
+
+    voter_weight_record.voter_weight_expiry = if received_voter_weight > 0 {
+        Some(clock.slot)
+    } else {
+        voter.voter_weight_expiry(registrar, clock.unix_timestamp, clock.slot)
+    };
+
+    // Set action and target to None to indicate the weight is valid for any action and target
+    voter_weight_record.weight_action = None;
+    voter_weight_record.weight_action_target = None;
+
+    Ok(())
+}
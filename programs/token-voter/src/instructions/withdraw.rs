@@ -9,6 +9,7 @@ use {
 
 #[derive(Accounts)]
 pub struct Withdraw<'info> {
+    #[account(mut)]
     pub registrar: Box<Account<'info, Registrar>>,
 
     // Checking the PDA address it just an extra precaution,
@@ -84,6 +85,9 @@ pub fn withdraw<'info>(
     let voter_authority_key = &ctx.accounts.voter_authority.key();
     let voter = &mut ctx.accounts.voter;
 
+    // The delegated vote weight must be taken back from the delegate first
+    require!(!voter.is_delegated(), TokenVoterError::VoterDelegated);
+
     {
         transfer_spl_tokens_signed_checked(
             &ctx.accounts.vault.to_account_info(),
@@ -99,7 +103,7 @@ pub fn withdraw<'info>(
     }
 
     // Load the accounts.
-    let registrar = &ctx.accounts.registrar;
+    let registrar = &mut ctx.accounts.registrar;
 
     // Get the exchange rate for the token being withdrawn,
     // fails if mint does not exist in the registrar config.
@@ -122,6 +126,10 @@ pub fn withdraw<'info>(
         TokenVoterError::MintNotFound
     );
 
+    let clock = Clock::get()?;
+    let current_slot_hash = clock.slot;
+    let curr_ts = clock.unix_timestamp;
+
     // Bookkeeping for withdrawn funds.
     require_gte!(
         deposit_entry.amount_deposited_native,
@@ -129,6 +137,23 @@ pub fn withdraw<'info>(
         TokenVoterError::TokenAmountOverflow
     );
 
+    let voting_mint_config = &mut registrar.voting_mint_configs[mint_idx];
+
+    // Locked tokens can't be withdrawn until they unlock
+    // unless the mint was retired and the tokens no longer grant any vote weight
+    if !voting_mint_config.retired {
+        require_gte!(
+            deposit_entry.amount_unlocked(curr_ts),
+            amount,
+            TokenVoterError::InsufficientUnlockedTokens
+        );
+    }
+
+    // Deposits made before the total was tracked are not included in it
+    voting_mint_config.total_deposited_native = voting_mint_config
+        .total_deposited_native
+        .saturating_sub(amount);
+
     deposit_entry.amount_deposited_native = deposit_entry
         .amount_deposited_native
         .checked_sub(amount)
@@ -136,11 +161,10 @@ pub fn withdraw<'info>(
 
     if deposit_entry.amount_deposited_native == 0 {
         deposit_entry.is_used = false;
+        deposit_entry.lockup = Lockup::default();
+        deposit_entry.amount_initially_locked_native = 0;
     }
 
-    let clock = Clock::get()?;
-    let current_slot_hash = clock.slot;
-
     // Using the slot hash to enforce withdrawal and depositing to be not
     // in the same slot to prevent flash loan style governance attacks
     require_neq!(
@@ -152,9 +176,10 @@ pub fn withdraw<'info>(
     // Update the voter weight record
     let voter_weight_record = &mut ctx.accounts.voter_weight_record;
     voter_weight_record.voter_weight = voter.weight(registrar)?;
-    // Voter Weight Expiry is always set to None after a deposit
-    // since no other action other than deposit and withdraw could invalidate it
-    voter_weight_record.voter_weight_expiry = None;
+    // Voter Weight Expiry is set to None after a withdraw unless the weight
+    // decays with the remaining lockup time and must be refreshed before it's used
+    voter_weight_record.voter_weight_expiry =
+        voter.voter_weight_expiry(registrar, curr_ts, current_slot_hash);
 
     Ok(())
 }
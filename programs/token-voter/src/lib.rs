@@ -6,6 +6,7 @@ mod instructions;
 use instructions::*;
 
 pub mod state;
+use state::LockupKind;
 
 mod governance;
 pub mod tools;
@@ -39,12 +40,35 @@ pub mod token_voter {
         instructions::create_max_voter_weight_record(ctx)
     }
 
+    pub fn update_max_voter_weight_record<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateMaxVoterWeightRecord<'info>>,
+    ) -> Result<()> {
+        log_version();
+        instructions::update_max_voter_weight_record(ctx)
+    }
+
     pub fn configure_mint_config(
         ctx: Context<ConfigureVotingMintConfig>,
         digit_shift: i8,
+        lockup_saturation_secs: u64,
+        max_extra_lockup_vote_weight_scaled_factor: u64,
+        weight_multiplier_numerator: u64,
+        weight_multiplier_denominator: u64,
     ) -> Result<()> {
         log_version();
-        instructions::configure_mint_config(ctx, digit_shift)
+        instructions::configure_mint_config(
+            ctx,
+            digit_shift,
+            lockup_saturation_secs,
+            max_extra_lockup_vote_weight_scaled_factor,
+            weight_multiplier_numerator,
+            weight_multiplier_denominator,
+        )
+    }
+
+    pub fn remove_mint_config(ctx: Context<RemoveMintConfig>) -> Result<()> {
+        log_version();
+        instructions::remove_mint_config(ctx)
     }
 
     pub fn deposit<'info>(
@@ -65,6 +89,31 @@ pub mod token_voter {
         instructions::withdraw(ctx, deposit_entry_index, amount)
     }
 
+    pub fn reset_lockup(
+        ctx: Context<ResetLockup>,
+        deposit_entry_index: u8,
+        kind: LockupKind,
+        periods: u32,
+    ) -> Result<()> {
+        log_version();
+        instructions::reset_lockup(ctx, deposit_entry_index, kind, periods)
+    }
+
+    pub fn update_voter_weight_record(ctx: Context<UpdateVoterWeightRecord>) -> Result<()> {
+        log_version();
+        instructions::update_voter_weight_record(ctx)
+    }
+
+    pub fn set_delegate(ctx: Context<SetDelegate>) -> Result<()> {
+        log_version();
+        instructions::set_delegate(ctx)
+    }
+
+    pub fn clear_delegate(ctx: Context<ClearDelegate>) -> Result<()> {
+        log_version();
+        instructions::clear_delegate(ctx)
+    }
+
     pub fn close_voter<'info>(ctx: Context<'_, '_, '_, 'info, CloseVoter<'info>>) -> Result<()> {
         log_version();
         instructions::close_voter(ctx)
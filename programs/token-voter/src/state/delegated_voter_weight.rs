@@ -0,0 +1,138 @@
+use {
+    crate::error::TokenVoterError,
+    anchor_lang::{prelude::*, Discriminator},
+    solana_program::pubkey::PUBKEY_BYTES,
+};
+
+/// Aggregate of the vote weight delegated to a delegate by other voters.
+#[account]
+#[derive(Debug, PartialEq)]
+pub struct DelegatedVoterWeight {
+    /// Registrar in which the delegation is made in.
+    pub registrar: Pubkey,
+
+    /// The delegate who can vote with the delegated weight.
+    pub delegate: Pubkey,
+
+    /// Sum of the vote weight contributed by all delegators
+    /// as of their last update with the DelegatedVoterWeight provided.
+    /// It's informational only and not used for voting,
+    /// the delegated vote weight is computed from the delegators' Voter accounts at vote time.
+    pub voter_weight: u64,
+
+    /// Number of voters delegating to the delegate.
+    pub delegators_count: u32,
+
+    /// DelegatedVoterWeight account bump.
+    pub bump: u8,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 64],
+}
+
+impl DelegatedVoterWeight {
+    pub fn get_space() -> usize {
+        DelegatedVoterWeight::discriminator().len() + PUBKEY_BYTES * 2 + 8 + 4 + 1 + 64
+    }
+
+    /// Replaces the weight previously contributed by a delegator with its current weight.
+    pub fn update_voter_weight(&mut self, previous_weight: u64, current_weight: u64) -> Result<()> {
+        self.voter_weight = self
+            .voter_weight
+            .checked_sub(previous_weight)
+            .and_then(|weight| weight.checked_add(current_weight))
+            .ok_or_else(|| error!(TokenVoterError::VoterWeightOverflow))?;
+
+        Ok(())
+    }
+}
+
+/// Returns DelegatedVoterWeight PDA seeds
+pub fn get_delegated_voter_weight_seeds<'a>(
+    registrar: &'a Pubkey,
+    delegate: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        registrar.as_ref(),
+        b"delegated-voter-weight",
+        delegate.as_ref(),
+    ]
+}
+
+/// Returns DelegatedVoterWeight PDA address
+pub fn get_delegated_voter_weight_address(registrar: &Pubkey, delegate: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &get_delegated_voter_weight_seeds(registrar, delegate),
+        &crate::id(),
+    )
+    .0
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_get_space() {
+        // Arrange
+        let expected_space = DelegatedVoterWeight::get_space();
+
+        let delegated_voter_weight = DelegatedVoterWeight {
+            registrar: Pubkey::default(),
+            delegate: Pubkey::default(),
+            voter_weight: 0,
+            delegators_count: 0,
+            bump: 0,
+            reserved: [0; 64],
+        };
+
+        // Act
+        let actual_space = DelegatedVoterWeight::discriminator().len()
+            + delegated_voter_weight.try_to_vec().unwrap().len();
+
+        // Assert
+        assert_eq!(expected_space, actual_space);
+    }
+
+    #[test]
+    fn test_update_voter_weight() {
+        // Arrange
+        let mut delegated_voter_weight = DelegatedVoterWeight {
+            registrar: Pubkey::default(),
+            delegate: Pubkey::default(),
+            voter_weight: 100,
+            delegators_count: 2,
+            bump: 0,
+            reserved: [0; 64],
+        };
+
+        // Act
+        delegated_voter_weight.update_voter_weight(40, 70).unwrap();
+
+        // Assert
+        assert_eq!(delegated_voter_weight.voter_weight, 130);
+    }
+
+    #[test]
+    fn test_update_voter_weight_with_underflow_error() {
+        // Arrange
+        let mut delegated_voter_weight = DelegatedVoterWeight {
+            registrar: Pubkey::default(),
+            delegate: Pubkey::default(),
+            voter_weight: 10,
+            delegators_count: 1,
+            bump: 0,
+            reserved: [0; 64],
+        };
+
+        // Act
+        let err = delegated_voter_weight
+            .update_voter_weight(40, 70)
+            .err()
+            .unwrap();
+
+        // Assert
+        assert_eq!(err, error!(TokenVoterError::VoterWeightOverflow));
+    }
+}
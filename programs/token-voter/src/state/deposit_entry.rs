@@ -1,4 +1,8 @@
-use {crate::state::VotingMintConfig, anchor_lang::prelude::*};
+use {
+    crate::{error::*, state::*},
+    anchor_lang::prelude::*,
+    std::convert::TryFrom,
+};
 
 /// Bookkeeping for a single deposit for a given mint.
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
@@ -20,11 +24,21 @@ pub struct DepositEntry {
     // True if the deposit entry is being used.
     pub is_used: bool,
 
+    /// Lockup of the deposit.
+    pub lockup: Lockup,
+
+    /// Amount in locked when the lockup began, in native currency.
+    ///
+    /// Note that this is not adjusted for withdraws. It is possible for this
+    /// value to be bigger than amount_deposited_native after some vesting
+    /// and withdrawals.
+    pub amount_initially_locked_native: u64,
+
     /// Reserved for future upgrades
-    pub reserved: [u8; 38],
+    pub reserved: [u8; 6],
 }
 
-const_assert!(std::mem::size_of::<DepositEntry>() == 8 + 1 + 8 + 1 + 38);
+const_assert!(std::mem::size_of::<DepositEntry>() == 8 + 1 + 8 + 1 + 24 + 8 + 6);
 const_assert!(std::mem::size_of::<DepositEntry>() % 8 == 0);
 
 impl DepositEntry {
@@ -35,7 +49,9 @@ impl DepositEntry {
             voting_mint_config_idx: 0,
             deposit_slot_hash: 0,
             is_used: false,
-            reserved: [0; 38],
+            lockup: Lockup::default(),
+            amount_initially_locked_native: 0,
+            reserved: [0; 6],
         }
     }
     /// Initializes a vector of DepositEntry with a given length
@@ -45,10 +61,180 @@ impl DepositEntry {
 
     /// Voting Power Caclulation
     /// Returns the voting power for the deposit.
-    pub fn voting_power(&self, mint_config: &VotingMintConfig) -> Result<u64> {
-        let vote_weight = mint_config.digit_shift_native(self.amount_deposited_native)?;
+    ///
+    /// The baseline vote weight is the digit shifted deposited amount scaled by the weight multiplier.
+    /// Locked tokens add an extra vote weight of up to
+    /// max_extra_lockup_vote_weight_scaled_factor / SCALED_FACTOR_BASE of the locked amount,
+    /// scaled linearly by the remaining lockup time until lockup_saturation_secs is reached.
+    pub fn voting_power(&self, mint_config: &VotingMintConfig, curr_ts: i64) -> Result<u64> {
+        let baseline_vote_weight =
+            mint_config.baseline_vote_weight(self.amount_deposited_native)?;
+
+        let max_locked_vote_weight =
+            mint_config.max_extra_lockup_vote_weight(self.amount_locked(curr_ts))?;
+
+        let locked_vote_weight = self.voting_power_locked(
+            curr_ts,
+            max_locked_vote_weight,
+            mint_config.lockup_saturation_secs,
+        )?;
+
+        baseline_vote_weight
+            .checked_add(locked_vote_weight)
+            .ok_or_else(|| error!(TokenVoterError::VoterWeightOverflow))
+    }
+
+    /// Vote power contribution from locked funds only.
+    pub fn voting_power_locked(
+        &self,
+        curr_ts: i64,
+        max_locked_vote_weight: u64,
+        lockup_saturation_secs: u64,
+    ) -> Result<u64> {
+        if self.lockup.expired(curr_ts) || max_locked_vote_weight == 0 {
+            return Ok(0);
+        }
+
+        match self.lockup.kind {
+            LockupKind::None => Ok(0),
+            LockupKind::Cliff | LockupKind::Constant => Self::voting_power_cliff(
+                self.lockup.seconds_left(curr_ts),
+                max_locked_vote_weight,
+                lockup_saturation_secs,
+            ),
+            LockupKind::Daily => {
+                self.voting_power_daily(curr_ts, max_locked_vote_weight, lockup_saturation_secs)
+            }
+        }
+    }
+
+    /// Vote power contribution from funds which all unlock at the same time.
+    fn voting_power_cliff(
+        seconds_left: u64,
+        max_locked_vote_weight: u64,
+        lockup_saturation_secs: u64,
+    ) -> Result<u64> {
+        let vote_weight = (max_locked_vote_weight as u128)
+            .checked_mul(seconds_left.min(lockup_saturation_secs) as u128)
+            .and_then(|v| v.checked_div(lockup_saturation_secs as u128))
+            .and_then(|v| u64::try_from(v).ok());
+
+        vote_weight.ok_or_else(|| error!(TokenVoterError::VoterWeightOverflow))
+    }
+
+    /// Vote power contribution from funds which unlock in equal amounts every day.
+    ///
+    /// Each of the remaining periods unlocks 1/periods_left of the locked amount.
+    /// The tranche unlocking at the end of the k-th remaining period (k = 0..n)
+    /// has s + k * SECS_PER_DAY seconds left, where s is the time left in the current period.
+    /// Its weight is therefore capped at lockup_saturation_secs which gives
+    ///   max_weight / n * sum_k min(s + k * SECS_PER_DAY, saturation) / saturation
+    fn voting_power_daily(
+        &self,
+        curr_ts: i64,
+        max_locked_vote_weight: u64,
+        lockup_saturation_secs: u64,
+    ) -> Result<u64> {
+        let periods_left = self.lockup.periods_left(curr_ts) as u128;
+        if periods_left == 0 {
+            return Ok(0);
+        }
+
+        let period_secs = SECS_PER_DAY as u128;
+        let saturation = lockup_saturation_secs as u128;
+
+        let seconds_left = self.lockup.seconds_left(curr_ts) as u128;
+        let secs_to_period_end = match seconds_left % period_secs {
+            0 => period_secs,
+            secs => secs,
+        };
+
+        // Number of tranches which are below the saturation point
+        let unsaturated_periods = if secs_to_period_end >= saturation {
+            0
+        } else {
+            ((saturation - secs_to_period_end - 1) / period_secs + 1).min(periods_left)
+        };
+
+        let compute = || -> Option<u64> {
+            let unsaturated_secs = unsaturated_periods
+                .checked_mul(secs_to_period_end)?
+                .checked_add(
+                    period_secs
+                        .checked_mul(unsaturated_periods)?
+                        .checked_mul(unsaturated_periods.saturating_sub(1))?
+                        / 2,
+                )?;
+            let saturated_secs = (periods_left - unsaturated_periods).checked_mul(saturation)?;
+
+            let vote_weight = (max_locked_vote_weight as u128)
+                .checked_mul(unsaturated_secs.checked_add(saturated_secs)?)?
+                .checked_div(periods_left.checked_mul(saturation)?)?;
+
+            u64::try_from(vote_weight).ok()
+        };
+
+        compute().ok_or_else(|| error!(TokenVoterError::VoterWeightOverflow))
+    }
+
+    /// Returns the amount of locked tokens at curr_ts.
+    pub fn amount_locked(&self, curr_ts: i64) -> u64 {
+        let locked = match self.lockup.kind {
+            LockupKind::None => 0,
+            _ if self.lockup.expired(curr_ts) => 0,
+            LockupKind::Cliff | LockupKind::Constant => self.amount_initially_locked_native,
+            LockupKind::Daily => {
+                let periods_total = self.lockup.periods_total();
+                if periods_total == 0 {
+                    return 0;
+                }
+                ((self.amount_initially_locked_native as u128)
+                    * self.lockup.periods_left(curr_ts) as u128
+                    / periods_total as u128) as u64
+            }
+        };
+
+        // Withdrawals are only possible from unlocked tokens so the locked amount
+        // can never exceed the deposited amount
+        locked.min(self.amount_deposited_native)
+    }
+
+    /// Returns the amount of unlocked tokens at curr_ts.
+    pub fn amount_unlocked(&self, curr_ts: i64) -> u64 {
+        self.amount_deposited_native
+            .checked_sub(self.amount_locked(curr_ts))
+            .unwrap()
+    }
+
+    /// Re-baselines the lockup so that only the currently locked tokens are tracked
+    /// as initially locked.
+    ///
+    /// For Daily lockups the start is moved to the beginning of the current period
+    /// which keeps the remaining periods and the unlock schedule unchanged.
+    pub fn resolve_vesting(&mut self, curr_ts: i64) -> Result<()> {
+        if self.lockup.kind == LockupKind::None {
+            return Ok(());
+        }
+
+        if self.lockup.expired(curr_ts) {
+            self.lockup = Lockup::default();
+            self.amount_initially_locked_native = 0;
+            return Ok(());
+        }
+
+        self.amount_initially_locked_native = self.amount_locked(curr_ts);
+
+        if self.lockup.kind == LockupKind::Daily {
+            let periods_left = self.lockup.periods_left(curr_ts);
+            let period_secs = self.lockup.kind.period_secs();
+            self.lockup.start_ts = self
+                .lockup
+                .end_ts
+                .checked_sub(i64::try_from(periods_left * period_secs).unwrap())
+                .ok_or_else(|| error!(TokenVoterError::Overflow))?;
+        }
 
-        Ok(vote_weight)
+        Ok(())
     }
 }
 
@@ -57,3 +243,169 @@ impl Default for DepositEntry {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    const DAY: i64 = SECS_PER_DAY as i64;
+
+    fn create_mint_config(lockup_saturation_secs: u64, factor: u64) -> VotingMintConfig {
+        VotingMintConfig {
+            mint: Pubkey::new_unique(),
+            digit_shift: 0,
+            mint_supply: 0,
+            lockup_saturation_secs,
+            max_extra_lockup_vote_weight_scaled_factor: factor,
+            weight_multiplier_numerator: 1,
+            weight_multiplier_denominator: 1,
+            total_deposited_native: 0,
+            retired: false,
+            total_deposited_native_tracked: true,
+            reserved1: [0; 13],
+        }
+    }
+
+    fn create_deposit_entry(amount: u64, lockup: Lockup) -> DepositEntry {
+        DepositEntry {
+            amount_deposited_native: amount,
+            is_used: true,
+            lockup,
+            amount_initially_locked_native: amount,
+            ..DepositEntry::new()
+        }
+    }
+
+    #[test]
+    fn test_voting_power_without_lockup() {
+        // Arrange
+        let mint_config = create_mint_config(10 * SECS_PER_DAY, SCALED_FACTOR_BASE);
+        let deposit_entry = create_deposit_entry(1_000, Lockup::default());
+
+        // Act
+        let voting_power = deposit_entry.voting_power(&mint_config, 0).unwrap();
+
+        // Assert
+        assert_eq!(voting_power, 1_000);
+        assert_eq!(deposit_entry.amount_unlocked(0), 1_000);
+    }
+
+    #[test]
+    fn test_voting_power_with_weight_multiplier() {
+        // Arrange
+        let mut mint_config = create_mint_config(10 * SECS_PER_DAY, SCALED_FACTOR_BASE);
+        mint_config.weight_multiplier_numerator = 5;
+        mint_config.weight_multiplier_denominator = 2;
+
+        let lockup = Lockup::new_from_periods(LockupKind::Cliff, 0, 5).unwrap();
+        let deposit_entry = create_deposit_entry(1_000, lockup);
+
+        // Act
+        let voting_power = deposit_entry.voting_power(&mint_config, 0).unwrap();
+
+        // Assert
+        // 1_000 * 5 / 2 = 2_500 baseline + 2_500 * 5 / 10 extra lockup vote weight
+        assert_eq!(voting_power, 3_750);
+    }
+
+    #[test]
+    fn test_voting_power_cliff_lockup() {
+        // Arrange
+        let mint_config = create_mint_config(10 * SECS_PER_DAY, SCALED_FACTOR_BASE);
+        let lockup = Lockup::new_from_periods(LockupKind::Cliff, 0, 5).unwrap();
+        let deposit_entry = create_deposit_entry(1_000, lockup);
+
+        // Act & Assert
+        assert_eq!(deposit_entry.voting_power(&mint_config, 0).unwrap(), 1_500);
+        assert_eq!(
+            deposit_entry.voting_power(&mint_config, DAY).unwrap(),
+            1_400
+        );
+        assert_eq!(
+            deposit_entry.voting_power(&mint_config, 5 * DAY).unwrap(),
+            1_000
+        );
+        assert_eq!(deposit_entry.amount_unlocked(5 * DAY - 1), 0);
+        assert_eq!(deposit_entry.amount_unlocked(5 * DAY), 1_000);
+    }
+
+    #[test]
+    fn test_voting_power_cliff_lockup_saturated() {
+        // Arrange
+        let mint_config = create_mint_config(10 * SECS_PER_DAY, SCALED_FACTOR_BASE * 2);
+        let lockup = Lockup::new_from_periods(LockupKind::Cliff, 0, 20).unwrap();
+        let deposit_entry = create_deposit_entry(1_000, lockup);
+
+        // Act & Assert
+        assert_eq!(deposit_entry.voting_power(&mint_config, 0).unwrap(), 3_000);
+        assert_eq!(
+            deposit_entry.voting_power(&mint_config, 15 * DAY).unwrap(),
+            2_000
+        );
+    }
+
+    #[test]
+    fn test_voting_power_constant_lockup() {
+        // Arrange
+        let mint_config = create_mint_config(10 * SECS_PER_DAY, SCALED_FACTOR_BASE);
+        let lockup = Lockup::new_from_periods(LockupKind::Constant, 0, 5).unwrap();
+        let deposit_entry = create_deposit_entry(1_000, lockup);
+
+        // Act & Assert
+        assert_eq!(
+            deposit_entry.voting_power(&mint_config, 100 * DAY).unwrap(),
+            1_500
+        );
+        assert_eq!(deposit_entry.amount_unlocked(100 * DAY), 0);
+    }
+
+    #[test]
+    fn test_voting_power_daily_lockup() {
+        // Arrange
+        let mint_config = create_mint_config(10 * SECS_PER_DAY, SCALED_FACTOR_BASE);
+        let lockup = Lockup::new_from_periods(LockupKind::Daily, 0, 4).unwrap();
+        let deposit_entry = create_deposit_entry(1_000, lockup);
+
+        // Act
+        let voting_power = deposit_entry.voting_power(&mint_config, 0).unwrap();
+
+        // Assert
+        // 4 tranches of 250 unlocking in 1, 2, 3 and 4 days: 250 * (1 + 2 + 3 + 4) / 10 = 250
+        assert_eq!(voting_power, 1_250);
+        assert_eq!(deposit_entry.amount_locked(DAY), 750);
+        assert_eq!(deposit_entry.amount_unlocked(2 * DAY), 500);
+    }
+
+    #[test]
+    fn test_voting_power_daily_lockup_saturated() {
+        // Arrange
+        let mint_config = create_mint_config(2 * SECS_PER_DAY, SCALED_FACTOR_BASE);
+        let lockup = Lockup::new_from_periods(LockupKind::Daily, 0, 4).unwrap();
+        let deposit_entry = create_deposit_entry(1_000, lockup);
+
+        // Act
+        let voting_power = deposit_entry.voting_power(&mint_config, 0).unwrap();
+
+        // Assert
+        // 4 tranches of 250 unlocking in 1, 2, 3 and 4 days capped at 2 days:
+        // 250 * (1 + 2 + 2 + 2) / 2 = 875
+        assert_eq!(voting_power, 1_875);
+    }
+
+    #[test]
+    fn test_resolve_vesting_daily_lockup() {
+        // Arrange
+        let lockup = Lockup::new_from_periods(LockupKind::Daily, 0, 4).unwrap();
+        let mut deposit_entry = create_deposit_entry(1_000, lockup);
+
+        // Act
+        deposit_entry.resolve_vesting(DAY + 10).unwrap();
+
+        // Assert
+        assert_eq!(deposit_entry.amount_initially_locked_native, 750);
+        assert_eq!(deposit_entry.lockup.start_ts, DAY);
+        assert_eq!(deposit_entry.lockup.periods_total(), 3);
+        assert_eq!(deposit_entry.amount_locked(DAY + 10), 750);
+    }
+}
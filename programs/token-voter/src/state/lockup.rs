@@ -0,0 +1,215 @@
+use {crate::error::*, anchor_lang::prelude::*, std::convert::TryFrom};
+
+/// Seconds in one day.
+pub const SECS_PER_DAY: u64 = 86_400;
+
+/// Maximum number of days a deposit can be locked up for.
+pub const MAX_LOCKUP_PERIODS: u32 = 365 * 200;
+
+/// Kind of the lockup of a deposit.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockupKind {
+    /// No lockup, tokens can be withdrawn as long as not engaged in a proposal.
+    None,
+
+    /// Lock up the whole deposit until end_ts.
+    Cliff,
+
+    /// Lock up the whole deposit for the lockup duration which doesn't count down.
+    /// The lockup must be reset to Cliff to start unlocking.
+    Constant,
+
+    /// Lock up the deposit and unlock it in equal amounts every day until end_ts.
+    Daily,
+}
+
+impl LockupKind {
+    /// Lockups can only be reset to kinds of the same or a higher strictness.
+    pub fn strictness(&self) -> u8 {
+        match self {
+            LockupKind::None => 0,
+            LockupKind::Daily => 1,
+            LockupKind::Cliff | LockupKind::Constant => 2,
+        }
+    }
+
+    /// The duration of a lockup period in seconds.
+    pub fn period_secs(&self) -> u64 {
+        match self {
+            LockupKind::None => 0,
+            LockupKind::Cliff | LockupKind::Constant | LockupKind::Daily => SECS_PER_DAY,
+        }
+    }
+}
+
+/// Lockup of a deposit entry.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Lockup {
+    /// Start of the lockup.
+    /// For Daily lockups it's moved to the start of the current period when tokens are added.
+    pub start_ts: i64,
+
+    /// End of the lockup.
+    pub end_ts: i64,
+
+    /// Type of lockup.
+    pub kind: LockupKind,
+
+    /// Reserved for future upgrades
+    pub reserved: [u8; 7],
+}
+
+const_assert!(std::mem::size_of::<Lockup>() == 8 + 8 + 1 + 7);
+const_assert!(std::mem::size_of::<Lockup>() % 8 == 0);
+
+impl Default for Lockup {
+    fn default() -> Self {
+        Self {
+            start_ts: 0,
+            end_ts: 0,
+            kind: LockupKind::None,
+            reserved: [0; 7],
+        }
+    }
+}
+
+impl Lockup {
+    /// Creates a lockup starting at start_ts which lasts for the given number of periods.
+    pub fn new_from_periods(kind: LockupKind, start_ts: i64, periods: u32) -> Result<Self> {
+        require_gte!(
+            MAX_LOCKUP_PERIODS,
+            periods,
+            TokenVoterError::InvalidLockupPeriod
+        );
+
+        let duration_secs = (periods as u64)
+            .checked_mul(kind.period_secs())
+            .ok_or_else(|| error!(TokenVoterError::Overflow))?;
+
+        Ok(Self {
+            start_ts,
+            end_ts: start_ts
+                .checked_add(i64::try_from(duration_secs).unwrap())
+                .ok_or_else(|| error!(TokenVoterError::Overflow))?,
+            kind,
+            reserved: [0; 7],
+        })
+    }
+
+    /// Total duration of the lockup in seconds.
+    pub fn duration_secs(&self) -> u64 {
+        self.end_ts.saturating_sub(self.start_ts).max(0) as u64
+    }
+
+    /// Number of seconds left on the lockup.
+    /// For Constant lockups the full duration is always left.
+    pub fn seconds_left(&self, curr_ts: i64) -> u64 {
+        match self.kind {
+            LockupKind::None => 0,
+            LockupKind::Constant => self.duration_secs(),
+            LockupKind::Cliff | LockupKind::Daily => {
+                self.end_ts.saturating_sub(curr_ts).max(0) as u64
+            }
+        }
+    }
+
+    /// True when the lockup is finished and all tokens are unlocked.
+    pub fn expired(&self, curr_ts: i64) -> bool {
+        self.seconds_left(curr_ts) == 0
+    }
+
+    /// Number of lockup periods in total.
+    pub fn periods_total(&self) -> u64 {
+        let period_secs = self.kind.period_secs();
+        if period_secs == 0 {
+            return 0;
+        }
+
+        self.duration_secs() / period_secs
+    }
+
+    /// Number of lockup periods which haven't started unlocking yet including the current one.
+    pub fn periods_left(&self, curr_ts: i64) -> u64 {
+        let period_secs = self.kind.period_secs();
+        if period_secs == 0 {
+            return 0;
+        }
+
+        let seconds_left = self.seconds_left(curr_ts);
+        seconds_left
+            .checked_add(period_secs - 1)
+            .unwrap()
+            .checked_div(period_secs)
+            .unwrap()
+            .min(self.periods_total())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_cliff_lockup_seconds_left() {
+        // Arrange
+        let lockup = Lockup::new_from_periods(LockupKind::Cliff, 1_000, 2).unwrap();
+
+        // Act & Assert
+        assert_eq!(lockup.seconds_left(1_000), 2 * SECS_PER_DAY);
+        assert_eq!(
+            lockup.seconds_left(1_000 + SECS_PER_DAY as i64),
+            SECS_PER_DAY
+        );
+        assert_eq!(lockup.seconds_left(1_000 + 3 * SECS_PER_DAY as i64), 0);
+        assert!(lockup.expired(1_000 + 2 * SECS_PER_DAY as i64));
+    }
+
+    #[test]
+    fn test_constant_lockup_never_expires() {
+        // Arrange
+        let lockup = Lockup::new_from_periods(LockupKind::Constant, 1_000, 2).unwrap();
+
+        // Act & Assert
+        assert_eq!(
+            lockup.seconds_left(1_000 + 10 * SECS_PER_DAY as i64),
+            2 * SECS_PER_DAY
+        );
+        assert!(!lockup.expired(i64::MAX));
+    }
+
+    #[test]
+    fn test_daily_lockup_periods_left() {
+        // Arrange
+        let lockup = Lockup::new_from_periods(LockupKind::Daily, 0, 3).unwrap();
+
+        // Act & Assert
+        assert_eq!(lockup.periods_total(), 3);
+        assert_eq!(lockup.periods_left(0), 3);
+        assert_eq!(lockup.periods_left(1), 3);
+        assert_eq!(lockup.periods_left(SECS_PER_DAY as i64), 2);
+        assert_eq!(lockup.periods_left(3 * SECS_PER_DAY as i64 - 1), 1);
+        assert_eq!(lockup.periods_left(3 * SECS_PER_DAY as i64), 0);
+    }
+
+    #[test]
+    fn test_none_lockup_is_expired() {
+        // Arrange
+        let lockup = Lockup::default();
+
+        // Act & Assert
+        assert!(lockup.expired(0));
+        assert_eq!(lockup.periods_left(0), 0);
+    }
+
+    #[test]
+    fn test_new_from_periods_with_too_many_periods_error() {
+        // Act
+        let err = Lockup::new_from_periods(LockupKind::Cliff, 0, MAX_LOCKUP_PERIODS + 1)
+            .err()
+            .unwrap();
+
+        // Assert
+        assert_eq!(err, error!(TokenVoterError::InvalidLockupPeriod));
+    }
+}
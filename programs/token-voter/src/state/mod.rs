@@ -4,8 +4,14 @@ pub mod registrar;
 pub use voting_mint_config::*;
 pub mod voting_mint_config;
 
+pub use lockup::*;
+pub mod lockup;
+
 pub use deposit_entry::*;
 pub mod deposit_entry;
 
 pub use voter::*;
 pub mod voter;
+
+pub use delegated_voter_weight::*;
+pub mod delegated_voter_weight;
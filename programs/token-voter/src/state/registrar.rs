@@ -60,17 +60,43 @@ impl Registrar {
             .ok_or_else(|| error!(TokenVoterError::MintNotFound))
     }
 
+    /// Returns the mint index based on its position in voting_mint_config
+    /// and asserts the mint accepts deposits
+    pub fn active_voting_mint_config_index(&self, mint: Pubkey) -> Result<usize> {
+        let mint_idx = self.voting_mint_config_index(mint)?;
+        require!(
+            !self.voting_mint_configs[mint_idx].retired,
+            TokenVoterError::VotingMintRetired
+        );
+        Ok(mint_idx)
+    }
+
+    /// Returns the index a new voting mint should be stored at
+    /// Slots freed by removed mints are reused before the configs are extended
+    /// so the indexes of the existing mints never change
+    pub fn free_voting_mint_config_index(&self) -> usize {
+        self.voting_mint_configs
+            .iter()
+            .position(|vmc| !vmc.in_use())
+            .unwrap_or(self.voting_mint_configs.len())
+    }
+
     /// Returns the max vote weight based on the supply initially set for each mint
+    /// including the max extra lockup vote weight if the whole supply was locked up
     /// throws an error if the sum of the vote weights overflows
     pub fn max_vote_weight(&self) -> Result<u64> {
         self.voting_mint_configs
             .iter()
             .try_fold(0u64, |sum, mint_config| -> Result<u64> {
-                if !mint_config.in_use() {
+                if !mint_config.is_active() {
                     return Ok(sum);
                 }
                 let mint_supply = mint_config.mint_supply;
-                sum.checked_add(mint_config.digit_shift_native(mint_supply)?)
+                let baseline_vote_weight = mint_config.baseline_vote_weight(mint_supply)?;
+                let max_locked_vote_weight =
+                    mint_config.max_extra_lockup_vote_weight(mint_supply)?;
+                sum.checked_add(baseline_vote_weight)
+                    .and_then(|sum| sum.checked_add(max_locked_vote_weight))
                     .ok_or_else(|| error!(TokenVoterError::VoterWeightOverflow))
             })
     }
@@ -121,7 +147,7 @@ pub fn resolve_governing_token_owner(
 #[cfg(test)]
 mod test {
 
-    use super::*;
+    use {super::*, crate::state::SCALED_FACTOR_BASE};
 
     #[test]
     fn test_get_space() {
@@ -131,7 +157,14 @@ mod test {
             mint: Pubkey::default(),
             digit_shift: 0,
             mint_supply: 0,
-            reserved1: [0; 55],
+            lockup_saturation_secs: 0,
+            max_extra_lockup_vote_weight_scaled_factor: 0,
+            weight_multiplier_numerator: 0,
+            weight_multiplier_denominator: 0,
+            total_deposited_native: 0,
+            retired: false,
+            total_deposited_native_tracked: true,
+            reserved1: [0; 13],
         };
 
         let registrar = Registrar {
@@ -157,21 +190,42 @@ mod test {
             mint: Pubkey::new_unique(),
             digit_shift: 2,
             mint_supply: 1000,
-            reserved1: [0; 55],
+            lockup_saturation_secs: 0,
+            max_extra_lockup_vote_weight_scaled_factor: 0,
+            weight_multiplier_numerator: 0,
+            weight_multiplier_denominator: 0,
+            total_deposited_native: 0,
+            retired: false,
+            total_deposited_native_tracked: true,
+            reserved1: [0; 13],
         };
 
         let mint_config2 = VotingMintConfig {
             mint: Pubkey::new_unique(),
             digit_shift: 1,
             mint_supply: 500,
-            reserved1: [0; 55],
+            lockup_saturation_secs: 0,
+            max_extra_lockup_vote_weight_scaled_factor: 0,
+            weight_multiplier_numerator: 0,
+            weight_multiplier_denominator: 0,
+            total_deposited_native: 0,
+            retired: false,
+            total_deposited_native_tracked: true,
+            reserved1: [0; 13],
         };
 
         let mut mint_config3 = VotingMintConfig {
             mint: Pubkey::new_unique(),
             digit_shift: 0,
             mint_supply: 200,
-            reserved1: [0; 55],
+            lockup_saturation_secs: 0,
+            max_extra_lockup_vote_weight_scaled_factor: 0,
+            weight_multiplier_numerator: 0,
+            weight_multiplier_denominator: 0,
+            total_deposited_native: 0,
+            retired: false,
+            total_deposited_native_tracked: true,
+            reserved1: [0; 13],
         };
 
         let mut registrar = Registrar {
@@ -205,4 +259,152 @@ mod test {
         // Total: 100,000 + 5,000 + 200,000 = 305,000
         assert_eq!(max_weight_after_mod, 305000);
     }
+
+    #[test]
+    fn test_max_vote_weight_with_retired_mint() {
+        // Arrange
+        let mint_config1 = VotingMintConfig {
+            mint: Pubkey::new_unique(),
+            digit_shift: 0,
+            mint_supply: 1000,
+            lockup_saturation_secs: 0,
+            max_extra_lockup_vote_weight_scaled_factor: 0,
+            weight_multiplier_numerator: 1,
+            weight_multiplier_denominator: 1,
+            total_deposited_native: 0,
+            retired: false,
+            total_deposited_native_tracked: true,
+            reserved1: [0; 13],
+        };
+
+        let mint_config2 = VotingMintConfig {
+            mint: Pubkey::new_unique(),
+            retired: true,
+            total_deposited_native: 100,
+            ..mint_config1
+        };
+
+        let registrar = Registrar {
+            governance_program_id: Pubkey::default(),
+            voting_mint_configs: vec![mint_config1, mint_config2],
+            realm: Pubkey::default(),
+            governing_token_mint: Pubkey::default(),
+            max_mints: 2,
+            reserved: [0; 127],
+        };
+
+        // Act
+        let max_weight = registrar.max_vote_weight().unwrap();
+
+        // Assert
+        assert_eq!(max_weight, 1000);
+        assert_eq!(
+            registrar
+                .active_voting_mint_config_index(mint_config2.mint)
+                .err()
+                .unwrap(),
+            error!(TokenVoterError::VotingMintRetired)
+        );
+    }
+
+    #[test]
+    fn test_free_voting_mint_config_index() {
+        // Arrange
+        let mint_config = VotingMintConfig {
+            mint: Pubkey::new_unique(),
+            digit_shift: 0,
+            mint_supply: 0,
+            lockup_saturation_secs: 0,
+            max_extra_lockup_vote_weight_scaled_factor: 0,
+            weight_multiplier_numerator: 1,
+            weight_multiplier_denominator: 1,
+            total_deposited_native: 0,
+            retired: false,
+            total_deposited_native_tracked: true,
+            reserved1: [0; 13],
+        };
+
+        let mut registrar = Registrar {
+            governance_program_id: Pubkey::default(),
+            voting_mint_configs: vec![mint_config, mint_config],
+            realm: Pubkey::default(),
+            governing_token_mint: Pubkey::default(),
+            max_mints: 3,
+            reserved: [0; 127],
+        };
+
+        // Act & Assert
+        assert_eq!(registrar.free_voting_mint_config_index(), 2);
+
+        registrar.voting_mint_configs[0] = VotingMintConfig::default();
+        assert_eq!(registrar.free_voting_mint_config_index(), 0);
+    }
+
+    #[test]
+    fn test_max_vote_weight_with_weight_multiplier() {
+        // Arrange
+        let mint_config = VotingMintConfig {
+            mint: Pubkey::new_unique(),
+            digit_shift: 1,
+            mint_supply: 1000,
+            lockup_saturation_secs: 0,
+            max_extra_lockup_vote_weight_scaled_factor: 0,
+            weight_multiplier_numerator: 5,
+            weight_multiplier_denominator: 2,
+            total_deposited_native: 0,
+            retired: false,
+            total_deposited_native_tracked: true,
+            reserved1: [0; 13],
+        };
+
+        let registrar = Registrar {
+            governance_program_id: Pubkey::default(),
+            voting_mint_configs: vec![mint_config],
+            realm: Pubkey::default(),
+            governing_token_mint: Pubkey::default(),
+            max_mints: 1,
+            reserved: [0; 127],
+        };
+
+        // Act
+        let max_weight = registrar.max_vote_weight().unwrap();
+
+        // Assert
+        // 1000 * 10^1 * 5 / 2 = 25,000
+        assert_eq!(max_weight, 25000);
+    }
+
+    #[test]
+    fn test_max_vote_weight_with_lockup_factor() {
+        // Arrange
+        let mint_config = VotingMintConfig {
+            mint: Pubkey::new_unique(),
+            digit_shift: 1,
+            mint_supply: 1000,
+            lockup_saturation_secs: 100,
+            max_extra_lockup_vote_weight_scaled_factor: SCALED_FACTOR_BASE / 2,
+            weight_multiplier_numerator: 0,
+            weight_multiplier_denominator: 0,
+            total_deposited_native: 0,
+            retired: false,
+            total_deposited_native_tracked: true,
+            reserved1: [0; 13],
+        };
+
+        let registrar = Registrar {
+            governance_program_id: Pubkey::default(),
+            voting_mint_configs: vec![mint_config],
+            realm: Pubkey::default(),
+            governing_token_mint: Pubkey::default(),
+            max_mints: 1,
+            reserved: [0; 127],
+        };
+
+        // Act
+        let max_weight = registrar.max_vote_weight().unwrap();
+
+        // Assert
+        // 1000 * 10^1 = 10,000 baseline + 5,000 max extra lockup weight
+        assert_eq!(max_weight, 15000);
+    }
 }
@@ -1,5 +1,5 @@
 use {
-    super::{DepositEntry, Registrar},
+    super::{DelegatedVoterWeight, DepositEntry, LockupKind, Registrar},
     crate::error::TokenVoterError,
     anchor_lang::{prelude::*, Discriminator},
     solana_program::pubkey::PUBKEY_BYTES,
@@ -25,15 +25,29 @@ pub struct Voter {
     /// Voter weight record account bump.
     pub voter_weight_record_bump: u8,
 
+    /// Delegate the vote weight of the deposits is delegated to.
+    /// Pubkey::default() if the vote weight is not delegated.
+    pub delegate: Pubkey,
+
+    /// Vote weight currently accounted for in the delegate's DelegatedVoterWeight.
+    pub delegated_voter_weight: u64,
+
     /// Reserved for future upgrades
-    pub reserved: [u8; 94],
+    pub reserved: [u8; 54],
 }
 
 const_assert!(std::mem::size_of::<Voter>() % 8 == 0);
 
 impl Voter {
     pub fn get_space(max_mints: u8) -> usize {
-        Voter::discriminator().len() + PUBKEY_BYTES * 2 + (max_mints as usize * 64) + 1 + 1 + 94
+        Voter::discriminator().len()
+            + PUBKEY_BYTES * 2
+            + (max_mints as usize * 64)
+            + 1
+            + 1
+            + PUBKEY_BYTES
+            + 8
+            + 54
     }
 
     pub fn clock_unix_timestamp(&self) -> i64 {
@@ -41,16 +55,99 @@ impl Voter {
     }
 
     /// The full vote weight available to the voter
+    /// including the extra weight of locked up deposits
+    /// Deposits of retired mints don't contribute to the vote weight
     pub fn weight(&self, registrar: &Registrar) -> Result<u64> {
+        let curr_ts = self.clock_unix_timestamp();
         self.deposits
             .iter()
             .filter(|d| d.is_used)
-            .try_fold(0u64, |sum, d| {
-                d.voting_power(&registrar.voting_mint_configs[d.voting_mint_config_idx as usize])
+            .map(|d| {
+                (
+                    d,
+                    &registrar.voting_mint_configs[d.voting_mint_config_idx as usize],
+                )
+            })
+            .filter(|(_, mint_config)| mint_config.is_active())
+            .try_fold(0u64, |sum, (d, mint_config)| {
+                d.voting_power(mint_config, curr_ts)
                     .map(|vp| sum.checked_add(vp).unwrap())
             })
     }
 
+    /// Whether the vote weight of the voter is delegated.
+    pub fn is_delegated(&self) -> bool {
+        self.delegate != Pubkey::default()
+    }
+
+    /// The vote weight the voter can use directly
+    /// It's 0 when the vote weight is delegated
+    pub fn undelegated_weight(&self, registrar: &Registrar) -> Result<u64> {
+        if self.is_delegated() {
+            return Ok(0);
+        }
+        self.weight(registrar)
+    }
+
+    /// Updates the vote weight accounted for in the delegate's DelegatedVoterWeight
+    /// with the current vote weight of the voter
+    /// The update is skipped when the DelegatedVoterWeight isn't provided because the aggregate is informational only
+    pub fn update_delegated_voter_weight(
+        &mut self,
+        registrar: &Registrar,
+        delegated_voter_weight: Option<&mut DelegatedVoterWeight>,
+    ) -> Result<()> {
+        if !self.is_delegated() {
+            return Ok(());
+        }
+
+        let delegated_voter_weight = match delegated_voter_weight {
+            Some(delegated_voter_weight) => delegated_voter_weight,
+            None => return Ok(()),
+        };
+
+        require_keys_eq!(
+            delegated_voter_weight.registrar,
+            self.registrar,
+            TokenVoterError::InvalidDelegatedVoterWeight
+        );
+        require_keys_eq!(
+            delegated_voter_weight.delegate,
+            self.delegate,
+            TokenVoterError::InvalidDelegatedVoterWeight
+        );
+
+        let weight = self.weight(registrar)?;
+        delegated_voter_weight.update_voter_weight(self.delegated_voter_weight, weight)?;
+        self.delegated_voter_weight = weight;
+
+        Ok(())
+    }
+
+    /// Returns the expiry of the voter weight computed at curr_ts
+    /// The weight of Cliff and Daily lockups decays over time so it's only valid for the current slot,
+    /// otherwise it stays valid until the next deposit or withdraw
+    pub fn voter_weight_expiry(
+        &self,
+        registrar: &Registrar,
+        curr_ts: i64,
+        current_slot: u64,
+    ) -> Option<u64> {
+        let has_decaying_lockup = self.deposits.iter().filter(|d| d.is_used).any(|d| {
+            let mint_config = &registrar.voting_mint_configs[d.voting_mint_config_idx as usize];
+            mint_config.is_active()
+                && mint_config.max_extra_lockup_vote_weight_scaled_factor > 0
+                && matches!(d.lockup.kind, LockupKind::Cliff | LockupKind::Daily)
+                && !d.lockup.expired(curr_ts)
+        });
+
+        if has_decaying_lockup {
+            Some(current_slot)
+        } else {
+            None
+        }
+    }
+
     pub fn active_deposit_mut(&mut self, index: u8) -> Result<&mut DepositEntry> {
         let index = index as usize;
         require_gt!(
@@ -2,11 +2,15 @@ use crate::error::*;
 use anchor_lang::prelude::*;
 use std::convert::TryFrom;
 
+/// Base of the fixed point max_extra_lockup_vote_weight_scaled_factor.
+/// A factor of SCALED_FACTOR_BASE doubles the vote weight of fully saturated locked tokens.
+pub const SCALED_FACTOR_BASE: u64 = 1_000_000_000;
+
 /// Exchange rate for an asset that can be used to mint voting rights.
 ///
 /// See documentation of configure_voting_mint for details on how
 /// native token amounts convert to vote weight.
-#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Default)]
 pub struct VotingMintConfig {
     /// Mint for this entry.
     pub mint: Pubkey,
@@ -17,11 +21,42 @@ pub struct VotingMintConfig {
     // The mint_supply is used to calculate the vote weight
     pub mint_supply: u64,
 
+    /// Number of seconds of remaining lockup at which the extra lockup vote weight
+    /// reaches its maximum. Longer lockups don't add more weight.
+    pub lockup_saturation_secs: u64,
+
+    /// Vote weight factor for locked tokens on top of the baseline vote weight,
+    /// in 1/SCALED_FACTOR_BASE units, reached at lockup_saturation_secs.
+    pub max_extra_lockup_vote_weight_scaled_factor: u64,
+
+    /// Numerator of the vote weight multiplier applied after the digit shift.
+    pub weight_multiplier_numerator: u64,
+
+    /// Denominator of the vote weight multiplier applied after the digit shift.
+    /// Configs created before the multiplier was introduced have it set to 0
+    /// which is treated as no multiplier.
+    pub weight_multiplier_denominator: u64,
+
+    /// Total amount of native tokens held in the vaults of all voters for this mint.
+    /// The slot of a retired mint can only be freed once all tokens are withdrawn.
+    pub total_deposited_native: u64,
+
+    /// True if the mint has been retired.
+    /// Retired mints don't accept deposits and don't contribute to the vote weight
+    /// but the existing deposits can still be withdrawn.
+    pub retired: bool,
+
+    /// True if total_deposited_native has been tracked since the mint was configured.
+    /// Mints configured before the total was tracked can have deposits which are not included in it.
+    pub total_deposited_native_tracked: bool,
+
     // Empty bytes for future upgrades.
-    pub reserved1: [u8; 55],
+    pub reserved1: [u8; 13],
 }
 
-const_assert!(std::mem::size_of::<VotingMintConfig>() == 32 + 1 + 8 + 55);
+const_assert!(
+    std::mem::size_of::<VotingMintConfig>() == 32 + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 13
+);
 const_assert!(std::mem::size_of::<VotingMintConfig>() % 8 == 0);
 
 impl VotingMintConfig {
@@ -45,8 +80,123 @@ impl VotingMintConfig {
         Self::compute_digit_shift_native(self.digit_shift, amount_native)
     }
 
+    /// Applies the weight_multiplier_numerator / weight_multiplier_denominator multiplier
+    pub fn apply_weight_multiplier(&self, vote_weight: u64) -> Result<u64> {
+        if self.weight_multiplier_denominator == 0 {
+            return Ok(vote_weight);
+        }
+
+        let compute = || -> Option<u64> {
+            let val = (vote_weight as u128)
+                .checked_mul(self.weight_multiplier_numerator as u128)?
+                .checked_div(self.weight_multiplier_denominator as u128)?;
+            u64::try_from(val).ok()
+        };
+        compute().ok_or_else(|| error!(TokenVoterError::VoterWeightOverflow))
+    }
+
+    /// Converts an amount in this voting mints's native currency
+    /// to the baseline vote weight by applying the digit_shift factor
+    /// followed by the weight multiplier.
+    pub fn baseline_vote_weight(&self, amount_native: u64) -> Result<u64> {
+        self.apply_weight_multiplier(self.digit_shift_native(amount_native)?)
+    }
+
+    /// The maximum extra vote weight a fully saturated lockup of amount_native can get.
+    pub fn max_extra_lockup_vote_weight(&self, amount_native: u64) -> Result<u64> {
+        let compute = || -> Option<u64> {
+            let vote_weight = (self.baseline_vote_weight(amount_native).ok()? as u128)
+                .checked_mul(self.max_extra_lockup_vote_weight_scaled_factor as u128)?
+                .checked_div(SCALED_FACTOR_BASE as u128)?;
+            u64::try_from(vote_weight).ok()
+        };
+        compute().ok_or_else(|| error!(TokenVoterError::VoterWeightOverflow))
+    }
+
+    /// Asserts the weight multiplier and lockup configuration are consistent.
+    pub fn assert_is_valid(&self) -> Result<()> {
+        require_gt!(
+            self.weight_multiplier_denominator,
+            0,
+            TokenVoterError::InvalidWeightMultiplier
+        );
+
+        if self.max_extra_lockup_vote_weight_scaled_factor > 0 {
+            require_gt!(
+                self.lockup_saturation_secs,
+                0,
+                TokenVoterError::InvalidLockupSaturation
+            );
+        }
+        Ok(())
+    }
+
     /// Whether this voting mint is configured.
     pub fn in_use(&self) -> bool {
         self.mint != Pubkey::default()
     }
+
+    /// Whether this voting mint is configured and contributes to the vote weight.
+    pub fn is_active(&self) -> bool {
+        self.in_use() && !self.retired
+    }
+
+    /// Whether voters can still hold deposits of this voting mint.
+    /// Deposits of mints configured before total_deposited_native was tracked can't be ruled out.
+    pub fn has_deposits(&self) -> bool {
+        !self.total_deposited_native_tracked || self.total_deposited_native > 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_has_deposits() {
+        // Arrange
+        let voting_mint_config = VotingMintConfig {
+            total_deposited_native: 100,
+            total_deposited_native_tracked: true,
+            ..VotingMintConfig::default()
+        };
+
+        // Act
+        let has_deposits = voting_mint_config.has_deposits();
+
+        // Assert
+        assert!(has_deposits);
+    }
+
+    #[test]
+    fn test_has_deposits_with_all_deposits_withdrawn() {
+        // Arrange
+        let voting_mint_config = VotingMintConfig {
+            total_deposited_native: 0,
+            total_deposited_native_tracked: true,
+            ..VotingMintConfig::default()
+        };
+
+        // Act
+        let has_deposits = voting_mint_config.has_deposits();
+
+        // Assert
+        assert!(!has_deposits);
+    }
+
+    #[test]
+    fn test_has_deposits_with_untracked_total() {
+        // Arrange
+        let voting_mint_config = VotingMintConfig {
+            total_deposited_native: 0,
+            total_deposited_native_tracked: false,
+            ..VotingMintConfig::default()
+        };
+
+        // Act
+        let has_deposits = voting_mint_config.has_deposits();
+
+        // Assert
+        assert!(has_deposits);
+    }
 }
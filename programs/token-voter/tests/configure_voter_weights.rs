@@ -1,3 +1,4 @@
+use gpl_token_voter::error::TokenVoterError;
 use program_test::token_voter_test::TokenVoterTest;
 use program_test::tools::*;
 use solana_program_test::*;
@@ -191,7 +192,11 @@ async fn test_configure_voter_weights_with_realm_authority_must_sign_error(
             &governance_program_cookie,
             &max_voter_weight_record_cookie,
             first_mint_cookie,
-            0,                                   // no digit shift,
+            0, // no digit shift,
+            0, // no lockup saturation
+            0, // no extra lockup vote weight
+            1, // no weight multiplier
+            1,
             |i| i.accounts[2].is_signer = false, // realm_authority
             Some(&[]),
         )
@@ -204,3 +209,97 @@ async fn test_configure_voter_weights_with_realm_authority_must_sign_error(
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_configure_voter_weights_with_weight_multiplier() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    token_voter_test
+        .configure_mint_config_using_ix(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+            0, // no lockup saturation
+            0, // no extra lockup vote weight
+            5, // 1 token = 2.5 votes
+            2,
+            NopOverride,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let registrar = token_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    let voting_mint_config = registrar.voting_mint_configs.first().unwrap();
+    assert_eq!(voting_mint_config.weight_multiplier_numerator, 5);
+    assert_eq!(voting_mint_config.weight_multiplier_denominator, 2);
+
+    let max_voter_weight_record = token_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    // supply is 100
+    assert_eq!(max_voter_weight_record.max_voter_weight, 250);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_configure_voter_weights_with_zero_weight_multiplier_denominator_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    // Act
+    let err = token_voter_test
+        .configure_mint_config_using_ix(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+            0, // no lockup saturation
+            0, // no extra lockup vote weight
+            1,
+            0,
+            NopOverride,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_voter_err(err, TokenVoterError::InvalidWeightMultiplier);
+
+    Ok(())
+}
@@ -0,0 +1,899 @@
+use gpl_token_voter::error::TokenVoterError;
+use gpl_token_voter::state::get_delegated_voter_weight_address;
+use program_test::token_voter_test::TokenVoterTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+use spl_governance::error::GovernanceError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_set_delegate() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let second_user_cookie = token_voter_test.users.get(1).unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let delegate_voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, second_user_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    let amount_deposited = 10_u64;
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            amount_deposited,
+            None,
+        )
+        .await?;
+
+    let delegate = second_user_cookie.key.pubkey();
+
+    // Act
+    token_voter_test
+        .set_delegate(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &delegate,
+        )
+        .await?;
+
+    // Assert
+    let voter_data = token_voter_test.get_voter(&voter_cookie.address).await;
+    assert_eq!(voter_data.delegate, delegate);
+    assert_eq!(voter_data.delegated_voter_weight, amount_deposited);
+
+    let voter_weight_record = token_voter_test
+        .get_voter_weight_record(&voter_cookie.voter_weight_record)
+        .await;
+    assert_eq!(voter_weight_record.voter_weight, 0);
+
+    let delegated_voter_weight = token_voter_test
+        .get_delegated_voter_weight(&registrar_cookie, &delegate)
+        .await;
+    assert_eq!(delegated_voter_weight.voter_weight, amount_deposited);
+    assert_eq!(delegated_voter_weight.delegators_count, 1);
+
+    // The delegate's VoterWeightRecord includes the delegated weight
+    token_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &delegate_voter_cookie,
+            None,
+            &[&voter_cookie],
+        )
+        .await?;
+
+    let delegate_voter_weight_record = token_voter_test
+        .get_voter_weight_record(&delegate_voter_cookie.voter_weight_record)
+        .await;
+    assert_eq!(delegate_voter_weight_record.voter_weight, amount_deposited);
+    assert!(delegate_voter_weight_record.voter_weight_expiry.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deposit_with_delegate() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let second_user_cookie = token_voter_test.users.get(1).unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            10,
+            None,
+        )
+        .await?;
+
+    let delegate = second_user_cookie.key.pubkey();
+
+    token_voter_test
+        .set_delegate(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &delegate,
+        )
+        .await?;
+
+    token_voter_test.bench.advance_clock().await;
+
+    let delegated_voter_weight_address =
+        get_delegated_voter_weight_address(&registrar_cookie.address, &delegate);
+
+    // Act
+    token_voter_test
+        .deposit_entry_using_ix(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &first_mint_cookie,
+            &token_owner_record_cookie,
+            &spl_token::id(),
+            0,
+            5,
+            |i| {
+                // delegated_voter_weight
+                i.accounts[12].pubkey = delegated_voter_weight_address;
+                i.accounts[12].is_writable = true;
+            },
+            None,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let delegated_voter_weight = token_voter_test
+        .get_delegated_voter_weight(&registrar_cookie, &delegate)
+        .await;
+    assert_eq!(delegated_voter_weight.voter_weight, 15);
+
+    let voter_weight_record = token_voter_test
+        .get_voter_weight_record(&voter_cookie.voter_weight_record)
+        .await;
+    assert_eq!(voter_weight_record.voter_weight, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deposit_without_delegated_voter_weight() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            10,
+            None,
+        )
+        .await?;
+
+    token_voter_test
+        .set_delegate(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &Pubkey::new_unique(),
+        )
+        .await?;
+
+    token_voter_test.bench.advance_clock().await;
+
+    // Act
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            5,
+            None,
+        )
+        .await?;
+
+    // Assert
+    // The informational delegated weight isn't updated without the DelegatedVoterWeight
+    let voter_data = token_voter_test.get_voter(&voter_cookie.address).await;
+    assert_eq!(voter_data.delegated_voter_weight, 10);
+
+    let voter_weight_record = token_voter_test
+        .get_voter_weight_record(&voter_cookie.voter_weight_record)
+        .await;
+    assert_eq!(voter_weight_record.voter_weight, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_clear_delegate() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let second_user_cookie = token_voter_test.users.get(1).unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    let amount_deposited = 10_u64;
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            amount_deposited,
+            None,
+        )
+        .await?;
+
+    let delegate = second_user_cookie.key.pubkey();
+
+    token_voter_test
+        .set_delegate(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &delegate,
+        )
+        .await?;
+
+    token_voter_test.bench.advance_clock().await;
+
+    // Withdrawals are blocked while the vote weight is delegated
+    let err = token_voter_test
+        .withdraw_deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            amount_deposited,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    assert_token_voter_err(err, TokenVoterError::VoterDelegated);
+
+    // Act
+    token_voter_test
+        .clear_delegate(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &delegate,
+        )
+        .await?;
+
+    // Assert
+    let voter_data = token_voter_test.get_voter(&voter_cookie.address).await;
+    assert_eq!(voter_data.delegate, Pubkey::default());
+    assert_eq!(voter_data.delegated_voter_weight, 0);
+
+    let voter_weight_record = token_voter_test
+        .get_voter_weight_record(&voter_cookie.voter_weight_record)
+        .await;
+    assert_eq!(voter_weight_record.voter_weight, amount_deposited);
+
+    let delegated_voter_weight = token_voter_test
+        .get_delegated_voter_weight(&registrar_cookie, &delegate)
+        .await;
+    assert_eq!(delegated_voter_weight.voter_weight, 0);
+    assert_eq!(delegated_voter_weight.delegators_count, 0);
+
+    // The owner can withdraw once the delegate is cleared
+    token_voter_test
+        .withdraw_deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            amount_deposited,
+            None,
+        )
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_delegate_to_self_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    // Act
+    let err = token_voter_test
+        .set_delegate(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_user_cookie.key.pubkey(),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_voter_err(err, TokenVoterError::InvalidDelegate);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_delegate_with_unrelinquished_vote_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let second_user_cookie = token_voter_test.users.get(1).unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let delegate_voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, second_user_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    let amount_deposited = 10_u64;
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            amount_deposited,
+            None,
+        )
+        .await?;
+
+    let proposal_cookie = token_voter_test
+        .governance
+        .with_proposal(&realm_cookie)
+        .await?;
+
+    token_voter_test
+        .governance
+        .cast_vote(
+            &realm_cookie,
+            &proposal_cookie,
+            &voter_cookie,
+            &first_user_cookie.key,
+            &first_user_cookie.key,
+            &max_voter_weight_record_cookie.address,
+            &token_owner_record_cookie,
+        )
+        .await?;
+
+    let delegate = second_user_cookie.key.pubkey();
+
+    // Act
+    let err = token_voter_test
+        .set_delegate(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &delegate,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_ix_err(
+        err,
+        InstructionError::Custom(
+            GovernanceError::AllVotesMustBeRelinquishedToWithdrawGoverningTokens as u32,
+        ),
+    );
+
+    // The delegate can't vote again with the weight already used by the voter
+    token_voter_test
+        .update_voter_weight_record(&registrar_cookie, &delegate_voter_cookie, None, &[])
+        .await?;
+
+    let delegate_voter_weight_record = token_voter_test
+        .get_voter_weight_record(&delegate_voter_cookie.voter_weight_record)
+        .await;
+    assert_eq!(delegate_voter_weight_record.voter_weight, 0);
+
+    // The voter's own weight can't be passed as delegated weight either
+    let err = token_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &delegate_voter_cookie,
+            None,
+            &[&voter_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    assert_token_voter_err(err, TokenVoterError::InvalidDelegator);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_duplicate_delegator_error(
+) -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let second_user_cookie = token_voter_test.users.get(1).unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let delegate_voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, second_user_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            10,
+            None,
+        )
+        .await?;
+
+    token_voter_test
+        .set_delegate(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &second_user_cookie.key.pubkey(),
+        )
+        .await?;
+
+    // Act
+    let err = token_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &delegate_voter_cookie,
+            None,
+            &[&voter_cookie, &voter_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_voter_err(err, TokenVoterError::DuplicateDelegator);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_clear_delegate_with_unrelinquished_delegate_vote_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let second_user_cookie = token_voter_test.users.get(1).unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let delegate_voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, second_user_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    let delegate_token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &second_user_cookie)
+        .await?;
+
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            10,
+            None,
+        )
+        .await?;
+
+    let delegate = second_user_cookie.key.pubkey();
+
+    token_voter_test
+        .set_delegate(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &delegate,
+        )
+        .await?;
+
+    let proposal_cookie = token_voter_test
+        .governance
+        .with_proposal(&realm_cookie)
+        .await?;
+
+    // The delegate votes with the delegated weight
+    token_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &delegate_voter_cookie,
+            None,
+            &[&voter_cookie],
+        )
+        .await?;
+
+    token_voter_test
+        .governance
+        .cast_vote(
+            &realm_cookie,
+            &proposal_cookie,
+            &delegate_voter_cookie,
+            &second_user_cookie.key,
+            &second_user_cookie.key,
+            &max_voter_weight_record_cookie.address,
+            &delegate_token_owner_record_cookie,
+        )
+        .await?;
+
+    // Act
+    let err = token_voter_test
+        .clear_delegate(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &delegate,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    // The delegated weight can't be taken back and used again by the voter
+    assert_ix_err(
+        err,
+        InstructionError::Custom(
+            GovernanceError::AllVotesMustBeRelinquishedToWithdrawGoverningTokens as u32,
+        ),
+    );
+
+    let voter_data = token_voter_test.get_voter(&voter_cookie.address).await;
+    assert_eq!(voter_data.delegate, delegate);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_record_with_invalid_delegator_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let second_user_cookie = token_voter_test.users.get(1).unwrap();
+    let third_user_cookie = token_voter_test.users.get(2).unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let delegate_voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, second_user_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            10,
+            None,
+        )
+        .await?;
+
+    // The voter delegates to another wallet than the voter whose weight is updated
+    token_voter_test
+        .set_delegate(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &third_user_cookie.key.pubkey(),
+        )
+        .await?;
+
+    // Act
+    let err = token_voter_test
+        .update_voter_weight_record(
+            &registrar_cookie,
+            &delegate_voter_cookie,
+            None,
+            &[&voter_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_voter_err(err, TokenVoterError::InvalidDelegator);
+
+    Ok(())
+}
@@ -463,6 +463,58 @@ impl TokenVoterTest {
         })
     }
 
+    #[allow(dead_code)]
+    pub async fn update_max_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_cookie: &MaxVoterWeightRecordCookie,
+        mint_cookies: &[&MintCookie],
+    ) -> Result<(), BanksClientError> {
+        self.update_max_voter_weight_record_using_ix(
+            registrar_cookie,
+            max_voter_weight_cookie,
+            mint_cookies,
+            NopOverride,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_max_voter_weight_record_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_cookie: &MaxVoterWeightRecordCookie,
+        mint_cookies: &[&MintCookie],
+        instruction_override: F,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_token_voter::instruction::UpdateMaxVoterWeightRecord {},
+        );
+
+        let accounts = gpl_token_voter::accounts::UpdateMaxVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            max_voter_weight_record: max_voter_weight_cookie.address,
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        for mint_cookie in mint_cookies {
+            account_metas.push(AccountMeta::new_readonly(mint_cookie.address, false));
+        }
+
+        let mut update_max_voter_weight_record_ix = Instruction {
+            program_id: gpl_token_voter::id(),
+            accounts: account_metas,
+            data,
+        };
+
+        instruction_override(&mut update_max_voter_weight_record_ix);
+
+        self.bench
+            .process_transaction(&[update_max_voter_weight_record_ix], None)
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn configure_mint_config(
         &self,
@@ -478,6 +530,10 @@ impl TokenVoterTest {
             max_voter_weight_cookie,
             mint_cookie,
             digit_shift,
+            0, // no lockup saturation
+            0, // no extra lockup vote weight
+            1, // no weight multiplier
+            1,
             NopOverride,
             None,
         )
@@ -485,6 +541,7 @@ impl TokenVoterTest {
     }
 
     #[allow(dead_code)]
+    #[allow(clippy::too_many_arguments)]
     pub async fn configure_mint_config_using_ix<F: Fn(&mut Instruction)>(
         &self,
         registrar_cookie: &RegistrarCookie,
@@ -492,11 +549,21 @@ impl TokenVoterTest {
         max_voter_weight_cookie: &MaxVoterWeightRecordCookie,
         mint_cookie: &MintCookie,
         digit_shift: i8,
+        lockup_saturation_secs: u64,
+        max_extra_lockup_vote_weight_scaled_factor: u64,
+        weight_multiplier_numerator: u64,
+        weight_multiplier_denominator: u64,
         instruction_override: F,
         signers_override: Option<&[&Keypair]>,
     ) -> Result<VotingMintConfig, BanksClientError> {
         let data = anchor_lang::InstructionData::data(
-            &gpl_token_voter::instruction::ConfigureMintConfig { digit_shift },
+            &gpl_token_voter::instruction::ConfigureMintConfig {
+                digit_shift,
+                lockup_saturation_secs,
+                max_extra_lockup_vote_weight_scaled_factor,
+                weight_multiplier_numerator,
+                weight_multiplier_denominator,
+            },
         );
 
         let accounts = gpl_token_voter::accounts::ConfigureVotingMintConfig {
@@ -528,10 +595,100 @@ impl TokenVoterTest {
             digit_shift,
             // hard coded
             mint_supply: 100 * 10u64.pow(6),
-            reserved1: [0; 55],
+            lockup_saturation_secs,
+            max_extra_lockup_vote_weight_scaled_factor,
+            weight_multiplier_numerator,
+            weight_multiplier_denominator,
+            total_deposited_native: 0,
+            retired: false,
+            total_deposited_native_tracked: true,
+            reserved1: [0; 13],
         })
     }
 
+    #[allow(dead_code)]
+    pub async fn remove_mint_config(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_cookie: &MaxVoterWeightRecordCookie,
+        mint_cookie: &MintCookie,
+    ) -> Result<(), BanksClientError> {
+        self.remove_mint_config_using_ix(
+            registrar_cookie,
+            max_voter_weight_cookie,
+            mint_cookie,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn remove_mint_config_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        max_voter_weight_cookie: &MaxVoterWeightRecordCookie,
+        mint_cookie: &MintCookie,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_token_voter::instruction::RemoveMintConfig {});
+
+        let accounts = gpl_token_voter::accounts::RemoveMintConfig {
+            registrar: registrar_cookie.address,
+            realm: registrar_cookie.account.realm,
+            realm_authority: registrar_cookie.realm_authority.pubkey(),
+            mint: mint_cookie.address,
+            max_voter_weight_record: max_voter_weight_cookie.address,
+        };
+
+        let mut remove_mint_config_ix = Instruction {
+            program_id: gpl_token_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut remove_mint_config_ix);
+
+        let default_signers = &[&registrar_cookie.realm_authority];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[remove_mint_config_ix], Some(signers))
+            .await
+    }
+
+    /// Clears total_deposited_native_tracked of the voting mint config
+    /// to emulate a mint configured before the deposited total was tracked
+    #[allow(dead_code)]
+    pub async fn with_untracked_total_deposited_native(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        mint_config_idx: usize,
+    ) {
+        let mut registrar = self.get_registrar_account(&registrar_cookie.address).await;
+        registrar.voting_mint_configs[mint_config_idx].total_deposited_native = 0;
+        registrar.voting_mint_configs[mint_config_idx].total_deposited_native_tracked = false;
+
+        let mut registrar_account = self
+            .bench
+            .get_account(&registrar_cookie.address)
+            .await
+            .unwrap();
+
+        anchor_lang::AccountSerialize::try_serialize(
+            &registrar,
+            &mut &mut registrar_account.data[..],
+        )
+        .unwrap();
+
+        self.bench
+            .context
+            .borrow_mut()
+            .set_account(&registrar_cookie.address, &registrar_account.into());
+    }
+
     #[allow(dead_code)]
     pub async fn deposit_entry(
         &self,
@@ -605,6 +762,7 @@ impl TokenVoterTest {
             instructions: instructions::id(),
             system_program: System::id(),
             associated_token_program: AssociatedToken::id(),
+            delegated_voter_weight: None,
         }
         .to_account_metas(None);
 
@@ -732,6 +890,239 @@ impl TokenVoterTest {
         Ok(())
     }
 
+    #[allow(dead_code)]
+    pub async fn reset_lockup(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &VoterCookie,
+        user_cookie: &UserCookie,
+        deposit_entry_index: u8,
+        kind: LockupKind,
+        periods: u32,
+    ) -> Result<(), BanksClientError> {
+        self.reset_lockup_using_ix(
+            registrar_cookie,
+            voter_cookie,
+            user_cookie,
+            deposit_entry_index,
+            kind,
+            periods,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn reset_lockup_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &VoterCookie,
+        user_cookie: &UserCookie,
+        deposit_entry_index: u8,
+        kind: LockupKind,
+        periods: u32,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(&gpl_token_voter::instruction::ResetLockup {
+            deposit_entry_index,
+            kind,
+            periods,
+        });
+
+        let accounts = gpl_token_voter::accounts::ResetLockup {
+            registrar: registrar_cookie.address,
+            voter: voter_cookie.address,
+            voter_authority: user_cookie.key.pubkey(),
+            voter_weight_record: voter_cookie.voter_weight_record,
+            delegated_voter_weight: None,
+        };
+
+        let mut reset_lockup_ix = Instruction {
+            program_id: gpl_token_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut reset_lockup_ix);
+
+        let default_signers = &[&user_cookie.key];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[reset_lockup_ix], Some(signers))
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_voter_weight_record(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &VoterCookie,
+        delegate: Option<&Pubkey>,
+        delegators: &[&VoterCookie],
+    ) -> Result<(), BanksClientError> {
+        let data = anchor_lang::InstructionData::data(
+            &gpl_token_voter::instruction::UpdateVoterWeightRecord {},
+        );
+
+        let accounts = gpl_token_voter::accounts::UpdateVoterWeightRecord {
+            registrar: registrar_cookie.address,
+            voter: voter_cookie.address,
+            voter_weight_record: voter_cookie.voter_weight_record,
+            delegated_voter_weight: delegate.map(|delegate| {
+                get_delegated_voter_weight_address(&registrar_cookie.address, delegate)
+            }),
+        };
+
+        let mut account_metas = anchor_lang::ToAccountMetas::to_account_metas(&accounts, None);
+
+        // The Voter accounts of the delegators are passed as remaining accounts
+        account_metas.extend(
+            delegators
+                .iter()
+                .map(|delegator| AccountMeta::new_readonly(delegator.address, false)),
+        );
+
+        let update_voter_weight_record_ix = Instruction {
+            program_id: gpl_token_voter::id(),
+            accounts: account_metas,
+            data,
+        };
+
+        self.bench
+            .process_transaction(&[update_voter_weight_record_ix], None)
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn set_delegate(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &VoterCookie,
+        user_cookie: &UserCookie,
+        token_owner_record_cookie: &TokenOwnerRecordCookie,
+        delegate: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        self.set_delegate_using_ix(
+            registrar_cookie,
+            voter_cookie,
+            user_cookie,
+            token_owner_record_cookie,
+            delegate,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    #[allow(dead_code)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn set_delegate_using_ix<F: Fn(&mut Instruction)>(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &VoterCookie,
+        user_cookie: &UserCookie,
+        token_owner_record_cookie: &TokenOwnerRecordCookie,
+        delegate: &Pubkey,
+        instruction_override: F,
+        signers_override: Option<&[&Keypair]>,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_token_voter::instruction::SetDelegate {});
+
+        let accounts = gpl_token_voter::accounts::SetDelegate {
+            registrar: registrar_cookie.address,
+            voter: voter_cookie.address,
+            voter_authority: user_cookie.key.pubkey(),
+            token_owner_record: token_owner_record_cookie.address,
+            voter_weight_record: voter_cookie.voter_weight_record,
+            delegate: *delegate,
+            delegated_voter_weight: get_delegated_voter_weight_address(
+                &registrar_cookie.address,
+                delegate,
+            ),
+            system_program: System::id(),
+        };
+
+        let mut set_delegate_ix = Instruction {
+            program_id: gpl_token_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        instruction_override(&mut set_delegate_ix);
+
+        let default_signers = &[&user_cookie.key];
+        let signers = signers_override.unwrap_or(default_signers);
+
+        self.bench
+            .process_transaction(&[set_delegate_ix], Some(signers))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn clear_delegate(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        voter_cookie: &VoterCookie,
+        user_cookie: &UserCookie,
+        delegate: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let data =
+            anchor_lang::InstructionData::data(&gpl_token_voter::instruction::ClearDelegate {});
+
+        let delegate_token_owner_record =
+            spl_governance::state::token_owner_record::get_token_owner_record_address(
+                &registrar_cookie.account.governance_program_id,
+                &registrar_cookie.account.realm,
+                &registrar_cookie.account.governing_token_mint,
+                delegate,
+            );
+
+        let accounts = gpl_token_voter::accounts::ClearDelegate {
+            registrar: registrar_cookie.address,
+            voter: voter_cookie.address,
+            voter_authority: user_cookie.key.pubkey(),
+            voter_weight_record: voter_cookie.voter_weight_record,
+            delegated_voter_weight: get_delegated_voter_weight_address(
+                &registrar_cookie.address,
+                delegate,
+            ),
+            delegate_token_owner_record,
+        };
+
+        let clear_delegate_ix = Instruction {
+            program_id: gpl_token_voter::id(),
+            accounts: anchor_lang::ToAccountMetas::to_account_metas(&accounts, None),
+            data,
+        };
+
+        self.bench
+            .process_transaction(&[clear_delegate_ix], Some(&[&user_cookie.key]))
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn get_delegated_voter_weight(
+        &self,
+        registrar_cookie: &RegistrarCookie,
+        delegate: &Pubkey,
+    ) -> DelegatedVoterWeight {
+        self.bench
+            .get_anchor_account(get_delegated_voter_weight_address(
+                &registrar_cookie.address,
+                delegate,
+            ))
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn close_voter_account(
         &self,
@@ -0,0 +1,376 @@
+use gpl_token_voter::error::TokenVoterError;
+use program_test::token_voter_test::TokenVoterTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_remove_mint_config() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+    let second_mint_cookie = token_voter_test.mints.get(1).unwrap();
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    // Act
+    token_voter_test
+        .remove_mint_config(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+        )
+        .await?;
+
+    // Assert
+    let registrar = token_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    // The slot is freed in place
+    assert_eq!(registrar.voting_mint_configs.len(), 1);
+    assert_eq!(registrar.voting_mint_configs[0].mint, Pubkey::default());
+
+    let max_voter_weight_record = token_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 0);
+
+    // The freed slot is reused by the next configured mint
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            second_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let registrar = token_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.voting_mint_configs.len(), 1);
+    assert_eq!(
+        registrar.voting_mint_configs[0].mint,
+        second_mint_cookie.address
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remove_mint_config_with_deposits() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    let amount_deposited = 10_u64;
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            amount_deposited,
+            None,
+        )
+        .await?;
+
+    // Act
+    token_voter_test
+        .remove_mint_config(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+        )
+        .await?;
+
+    // Assert
+    let registrar = token_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    let voting_mint_config = registrar.voting_mint_configs.first().unwrap();
+    assert!(voting_mint_config.retired);
+    assert_eq!(voting_mint_config.total_deposited_native, amount_deposited);
+
+    let max_voter_weight_record = token_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 0);
+
+    token_voter_test.bench.advance_clock().await;
+
+    // Retired mints don't accept deposits
+    let err = token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            amount_deposited,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    assert_token_voter_err(err, TokenVoterError::VotingMintRetired);
+
+    // Existing deposits can be withdrawn
+    token_voter_test
+        .withdraw_deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            amount_deposited,
+            None,
+        )
+        .await?;
+
+    let voter_weight_record = token_voter_test
+        .get_voter_weight_record(&voter_cookie.voter_weight_record)
+        .await;
+
+    assert_eq!(voter_weight_record.voter_weight, 0);
+
+    // The slot is freed once all deposits are withdrawn
+    token_voter_test
+        .remove_mint_config(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+        )
+        .await?;
+
+    let registrar = token_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.voting_mint_configs[0].mint, Pubkey::default());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remove_mint_config_with_untracked_deposits() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    let amount_deposited = 10_u64;
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            amount_deposited,
+            None,
+        )
+        .await?;
+
+    // The deposit was made before the deposited total was tracked
+    token_voter_test
+        .with_untracked_total_deposited_native(&registrar_cookie, 0)
+        .await;
+
+    // Act
+    token_voter_test
+        .remove_mint_config(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+        )
+        .await?;
+
+    // Assert
+    let registrar = token_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    // The slot is not freed because the untracked deposit can't be ruled out
+    let voting_mint_config = registrar.voting_mint_configs.first().unwrap();
+    assert!(voting_mint_config.retired);
+    assert_eq!(voting_mint_config.mint, first_mint_cookie.address);
+
+    token_voter_test.bench.advance_clock().await;
+
+    // The untracked deposit can still be withdrawn
+    token_voter_test
+        .withdraw_deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            amount_deposited,
+            None,
+        )
+        .await?;
+
+    token_voter_test
+        .remove_mint_config(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+        )
+        .await?;
+
+    let registrar = token_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(
+        registrar.voting_mint_configs[0].mint,
+        first_mint_cookie.address
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remove_mint_config_with_realm_authority_must_sign_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    // Act
+    let err = token_voter_test
+        .remove_mint_config_using_ix(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            |i| i.accounts[2].is_signer = false, // realm_authority
+            Some(&[]),
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotSigner);
+
+    Ok(())
+}
@@ -0,0 +1,268 @@
+use gpl_token_voter::error::TokenVoterError;
+use gpl_token_voter::state::{LockupKind, SCALED_FACTOR_BASE, SECS_PER_DAY};
+use program_test::token_voter_test::TokenVoterTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_reset_lockup() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config_using_ix(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+            10 * SECS_PER_DAY,
+            SCALED_FACTOR_BASE,
+            1, // no weight multiplier
+            1,
+            NopOverride,
+            None,
+        )
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    let amount_deposited = 10_u64;
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            amount_deposited,
+            None,
+        )
+        .await?;
+
+    // Act
+    token_voter_test
+        .reset_lockup(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            0,
+            LockupKind::Cliff,
+            5,
+        )
+        .await?;
+
+    // Assert
+    let voter_data = token_voter_test.get_voter(&voter_cookie.address).await;
+    let deposit_entry = voter_data.deposits.first().unwrap();
+
+    assert_eq!(deposit_entry.lockup.kind, LockupKind::Cliff);
+    assert_eq!(deposit_entry.lockup.periods_total(), 5);
+    assert_eq!(
+        deposit_entry.amount_initially_locked_native,
+        amount_deposited
+    );
+
+    let voter_weight_record = token_voter_test
+        .get_voter_weight_record(&voter_cookie.voter_weight_record)
+        .await;
+
+    // 10 baseline + 10 * 5 / 10 extra lockup vote weight
+    assert_eq!(voter_weight_record.voter_weight, 15);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reset_lockup_with_shorter_lockup_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            10,
+            None,
+        )
+        .await?;
+
+    token_voter_test
+        .reset_lockup(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            0,
+            LockupKind::Cliff,
+            5,
+        )
+        .await?;
+
+    token_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = token_voter_test
+        .reset_lockup(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            0,
+            LockupKind::Cliff,
+            2,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_voter_err(err, TokenVoterError::InvalidLockupChange);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_withdraw_locked_deposit_error() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let voter_cookie = token_voter_test
+        .with_voter(&registrar_cookie, first_user_cookie)
+        .await?;
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    let token_owner_record_cookie = token_voter_test
+        .governance
+        .with_token_owner_record_using_user_cookie(&realm_cookie, &first_user_cookie)
+        .await?;
+
+    let amount_deposited = 10_u64;
+    token_voter_test
+        .deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            amount_deposited,
+            None,
+        )
+        .await?;
+
+    token_voter_test
+        .reset_lockup(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            0,
+            LockupKind::Cliff,
+            5,
+        )
+        .await?;
+
+    token_voter_test.bench.advance_clock().await;
+
+    // Act
+    let err = token_voter_test
+        .withdraw_deposit_entry(
+            &registrar_cookie,
+            &voter_cookie,
+            &first_user_cookie,
+            &token_owner_record_cookie,
+            &first_mint_cookie,
+            &spl_token::id(),
+            0,
+            amount_deposited,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_voter_err(err, TokenVoterError::InsufficientUnlockedTokens);
+
+    Ok(())
+}
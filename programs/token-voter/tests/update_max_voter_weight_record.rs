@@ -0,0 +1,159 @@
+use crate::program_test::program_test_bench::MintType;
+use gpl_token_voter::error::TokenVoterError;
+use program_test::token_voter_test::TokenVoterTest;
+use program_test::tools::*;
+use solana_program_test::*;
+use solana_sdk::signer::Signer;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record() -> Result<(), TransportError> {
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_user_cookie = token_voter_test.users.first().unwrap();
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    // Increase the supply of the voting mint by 50
+    token_voter_test
+        .bench
+        .with_tokens(
+            first_mint_cookie,
+            &first_user_cookie.key.pubkey(),
+            50,
+            &MintType::SplToken,
+            false,
+        )
+        .await?;
+
+    // Act
+    token_voter_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            &[first_mint_cookie],
+        )
+        .await?;
+
+    // Assert
+    let registrar = token_voter_test
+        .get_registrar_account(&registrar_cookie.address)
+        .await;
+
+    assert_eq!(registrar.voting_mint_configs[0].mint_supply, 150);
+
+    let max_voter_weight_record = token_voter_test
+        .get_max_voter_weight_record(&max_voter_weight_record_cookie.address)
+        .await;
+
+    assert_eq!(max_voter_weight_record.max_voter_weight, 150);
+    assert_eq!(max_voter_weight_record.max_voter_weight_expiry, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_missing_mint_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    // Act
+    let err = token_voter_test
+        .update_max_voter_weight_record(&registrar_cookie, &max_voter_weight_record_cookie, &[])
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_voter_err(err, TokenVoterError::InvalidVotingMintAccountsCount);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_max_voter_weight_record_with_invalid_mint_error() -> Result<(), TransportError>
+{
+    // Arrange
+    let mut token_voter_test = TokenVoterTest::start_new().await;
+
+    let realm_cookie = token_voter_test.governance.with_realm().await?;
+
+    let registrar_cookie = token_voter_test.with_registrar(&realm_cookie).await?;
+    let governance_program_cookie = token_voter_test.with_governance_program(None).await;
+
+    let first_mint_cookie = token_voter_test.mints.first().unwrap();
+    let second_mint_cookie = token_voter_test.mints.get(1).unwrap();
+
+    let max_voter_weight_record_cookie = token_voter_test
+        .with_max_voter_weight_record(&registrar_cookie)
+        .await?;
+
+    token_voter_test
+        .configure_mint_config(
+            &registrar_cookie,
+            &governance_program_cookie,
+            &max_voter_weight_record_cookie,
+            first_mint_cookie,
+            0, // no digit shift
+        )
+        .await?;
+
+    // Act
+    let err = token_voter_test
+        .update_max_voter_weight_record(
+            &registrar_cookie,
+            &max_voter_weight_record_cookie,
+            &[second_mint_cookie],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_token_voter_err(err, TokenVoterError::InvalidVotingMintAccount);
+
+    Ok(())
+}